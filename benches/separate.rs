@@ -0,0 +1,53 @@
+//! Rough timings for comparing ways of adding separators. Run with
+//! `cargo bench`.
+
+extern crate thousands;
+
+use std::time::{Duration, Instant};
+
+use thousands::{Separable, policies, separate_in_place};
+
+const ITERATIONS: usize = 100_000;
+
+fn time<F: FnMut()>(name: &str, mut f: F) {
+    let start = Instant::now();
+    for _ in 0 .. ITERATIONS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed();
+
+    println!("{:<40} {:>10.1} ns/iter",
+             name, elapsed.as_secs_f64() * 1e9 / ITERATIONS as f64);
+}
+
+fn main() {
+    let input = "1234567890123456789012345678901234567890".to_owned();
+    let policy = policies::COMMA_SEPARATOR;
+
+    time("separate_by_policy (40 digits)", || {
+        let result = input.separate_by_policy(policy);
+        assert!(result.len() > input.len());
+    });
+
+    let non_ascii = "\u{2212}1234567890123456789012345678901234567890".to_owned();
+
+    time("separate_by_policy (40 digits, non-ASCII)", || {
+        let result = non_ascii.separate_by_policy(policy);
+        assert!(result.len() > non_ascii.len());
+    });
+
+    let small: Vec<String> = (0 .. 100).map(|i| (i * 7 % 1000).to_string()).collect();
+
+    time("separate_by_policy (100 numbers, 1-3 digits)", || {
+        for input in &small {
+            let result = input.separate_by_policy(policy);
+            assert_eq!(result.len(), input.len());
+        }
+    });
+
+    time("separate_in_place (40 digits)", || {
+        let mut s = input.clone();
+        separate_in_place(&mut s, policy);
+        assert!(s.len() > input.len());
+    });
+}
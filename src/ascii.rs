@@ -0,0 +1,143 @@
+use super::SeparatorPolicy;
+use super::display::{find_span, is_malformed, push_after};
+use super::helpers::SeparatorIterator;
+use super::policies::OnMalformed;
+
+/// Adds separators to `s` according to `policy` a byte at a time, or returns
+/// `None` if `s`, the separator, or the digits aren’t all ASCII, or if the
+/// policy asks for anything beyond a single separator between groups.
+pub fn separate_ascii(s: &str, policy: &SeparatorPolicy, capacity: usize) -> Option<String> {
+    if !applies(s, policy) {
+        return None;
+    }
+
+    let (before, number, after, count) =
+        find_span(s, |c| policy.is_digit(c), |c| policy.is_ignorable(c));
+    if policy.on_malformed != OnMalformed::GroupFirst && is_malformed(after, policy) {
+        return None;
+    }
+
+    let separator = policy.separator.as_bytes()[0];
+    let mut iter  = SeparatorIterator::new(policy, count);
+    let mut bytes = Vec::with_capacity(capacity.max(s.len() + iter.sep_len()));
+
+    bytes.extend_from_slice(before.as_bytes());
+
+    for &b in number.as_bytes() {
+        if !policy.is_digit(b as char) {
+            continue;
+        }
+
+        bytes.push(b);
+        if iter.next() == Some(true) {
+            for _ in 0 .. iter.separators_here(policy.collapse_separators) {
+                bytes.push(separator);
+            }
+        }
+    }
+
+    // SAFETY: Every byte comes from `s` or is the separator, and `applies`
+    // checked that both are ASCII.
+    let mut result = unsafe { String::from_utf8_unchecked(bytes) };
+
+    if count > 0 {
+        push_after(&mut result, after, policy);
+    }
+
+    Some(result)
+}
+
+// Whether the byte-at-a-time path gives the same result as the general one.
+fn applies(s: &str, policy: &SeparatorPolicy) -> bool {
+    policy.only_inserts_separators()
+        && policy.separator.len() == 1
+        && policy.separator.is_ascii()
+        && policy.separators_after.is_empty()
+        && policy.major_separator.is_none()
+        && policy.separator_fn.is_none()
+        && !policy.bind_with_zwj
+        && policy.digits.iter().all(char::is_ascii)
+        && s.is_ascii()
+}
+
+#[cfg(test)]
+mod test {
+    use super::separate_ascii;
+    use super::super::{SeparatorPolicy, policies};
+    use super::super::display::separate_chars;
+
+    const INPUTS: &[&str] = &[
+        "", "0", "12", "123", "1234", "-1234567.891", "x=98765 units", "1_234_567",
+        "12345e67", "1.2.3456", "abc", "1234567890123456789012345678901234567890",
+    ];
+
+    fn assert_matches_general(policy: SeparatorPolicy) {
+        for s in INPUTS {
+            let expected = separate_chars(s, policy, 0);
+            assert_eq!( separate_ascii(s, &policy, 0).as_deref(), Some(&*expected),
+                        "input: {:?}", s );
+        }
+    }
+
+    #[test]
+    fn matches_general_path() {
+        assert_matches_general(policies::COMMA_SEPARATOR);
+        assert_matches_general(policies::SPACE_SEPARATOR);
+        assert_matches_general(policies::UNDERSCORE_SEPARATOR);
+        assert_matches_general(policies::HEX_FOUR);
+        assert_matches_general(SeparatorPolicy {
+            groups:              &[3, 0, 2],
+            ignorable:           &['_'],
+            fraction_pad:        Some((4, '0')),
+            avoid_orphan:        true,
+            .. policies::COMMA_SEPARATOR
+        });
+        assert_matches_general(SeparatorPolicy {
+            groups:              &[3, 0, 2],
+            collapse_separators: true,
+            pivot:               Some(3),
+            truncate_fraction:   Some(1),
+            .. policies::COMMA_SEPARATOR
+        });
+        assert_matches_general(SeparatorPolicy {
+            regroup:             true,
+            on_malformed:        policies::OnMalformed::GroupFirst,
+            first_group:         Some(2),
+            .. policies::UNDERSCORE_SEPARATOR
+        });
+    }
+
+    #[test]
+    fn falls_back_for_non_ascii() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_ascii("1234€", &policy, 0), None );
+        assert_eq!( separate_ascii("١٢٣٤", &SeparatorPolicy {
+            digits: &['١', '٢', '٣', '٤'],
+            .. policy
+        }, 0), None );
+        assert_eq!( separate_ascii("1234", &SeparatorPolicy {
+            separator: "\u{202F}",
+            .. policy
+        }, 0), None );
+        assert_eq!( separate_ascii("1234", &SeparatorPolicy {
+            separator: "--",
+            .. policy
+        }, 0), None );
+        assert_eq!( separate_ascii("1234", &SeparatorPolicy {
+            bind_with_zwj: true,
+            .. policy
+        }, 0), None );
+    }
+
+    #[test]
+    fn falls_back_for_malformed() {
+        let policy = SeparatorPolicy {
+            on_malformed: policies::OnMalformed::Passthrough,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_ascii("1234.5.6", &policy, 0), None );
+        assert_eq!( separate_ascii("1234.5", &policy, 0).as_deref(), Some("1,234.5") );
+    }
+}
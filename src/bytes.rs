@@ -0,0 +1,180 @@
+use std::iter::once;
+
+use super::{NonAsciiSeparator, SeparatorPolicy};
+use super::helpers::SeparatorIterator;
+
+/// Adds `separator` to the bytes of `input` according to `policy`’s grouping
+/// and digits, without requiring the input to be UTF-8.
+///
+/// Only ASCII characters of `policy.digits` and `policy.ignorable` are
+/// recognized, and `policy.separator` is ignored in favor of `separator`.
+/// The separator must be ASCII, so that separating valid UTF-8 input gives
+/// valid UTF-8 output; otherwise, this returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{NonAsciiSeparator, policies, separate_bytes};
+///
+/// assert_eq!( separate_bytes(b"-1234567", b",", policies::COMMA_SEPARATOR),
+///             Ok(b"-1,234,567".to_vec()) );
+/// assert_eq!( separate_bytes(b"1234", b"\xA0", policies::COMMA_SEPARATOR),
+///             Err(NonAsciiSeparator { byte: 0xA0, index: 0 }) );
+/// ```
+pub fn separate_bytes(input: &[u8], separator: &[u8], policy: SeparatorPolicy)
+                      -> Result<Vec<u8>, NonAsciiSeparator> {
+
+    if let Some(index) = separator.iter().position(|&b| !b.is_ascii()) {
+        return Err(NonAsciiSeparator { byte: separator[index], index });
+    }
+
+    let is_digit    = |&b: &u8| b.is_ascii() && policy.is_digit(b as char);
+    let is_ignored  = |&b: &u8| b.is_ascii() && policy.ignorable.contains(&(b as char));
+
+    let start     = input.iter().position(is_digit).unwrap_or(input.len());
+    let mut limit = start;
+
+    for (i, b) in input.iter().enumerate().skip(start) {
+        if is_digit(b) {
+            limit = i + 1;
+        } else if !is_ignored(b) {
+            break;
+        }
+    }
+
+    let number = &input[start .. limit];
+    let count  = number.iter().filter(|b| is_digit(b)).count();
+    let mut iter = SeparatorIterator::new(&policy, count);
+
+    let mut result = Vec::with_capacity(input.len() + iter.sep_len() * separator.len());
+
+    result.extend_from_slice(&input[.. start]);
+
+    for &digit in number.iter().filter(|b| is_digit(b)) {
+        result.push(digit);
+        if iter.next() == Some(true) {
+            for _ in 0 .. iter.separators_here(policy.collapse_separators) {
+                result.extend_from_slice(separator);
+            }
+        }
+    }
+
+    result.extend_from_slice(&input[limit ..]);
+
+    Ok(result)
+}
+
+/// Formats `data` as lowercase hexadecimal, two digits per byte, inserting
+/// `separator` after every `group` hex digits.
+///
+/// As in a hexdump, the groups are counted from the start of the data, so
+/// only the last group may be short. A `group` of zero inserts no separators.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::separate_hex_bytes;
+///
+/// assert_eq!( separate_hex_bytes(&[0xde, 0xad, 0xbe, 0xef], 4, " "), "dead beef" );
+/// assert_eq!( separate_hex_bytes(&[0x01, 0x02, 0x03], 2, ":"),        "01:02:03" );
+/// ```
+pub fn separate_hex_bytes(data: &[u8], group: usize, separator: &str) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let ndigits   = 2 * data.len();
+    let sep_count = if group == 0 || ndigits == 0 { 0 } else { (ndigits - 1) / group };
+
+    let mut result = String::with_capacity(ndigits + sep_count * separator.len());
+
+    let digits = data.iter()
+        .flat_map(|&byte| once(byte >> 4).chain(once(byte & 0xf)));
+
+    for (index, digit) in digits.enumerate() {
+        if index > 0 && group > 0 && index % group == 0 {
+            result.push_str(separator);
+        }
+        result.push(HEX_DIGITS[digit as usize] as char);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{SeparatorPolicy, policies};
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_bytes(b"1234567", b",", policy),
+                    Ok(b"1,234,567".to_vec()) );
+        assert_eq!( separate_bytes(b"\xff-1234.5\xfe", b"__", policy),
+                    Ok(b"\xff-1__234.5\xfe".to_vec()) );
+        assert_eq!( separate_bytes(b"", b",", policy),
+                    Ok(b"".to_vec()) );
+    }
+
+    #[test]
+    fn bytes_ignorable() {
+        let policy = SeparatorPolicy {
+            ignorable: &[' ', '\u{A0}'],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_bytes(b"1 234 567 ", b",", policy),
+                    Ok(b"1,234,567 ".to_vec()) );
+    }
+
+    #[test]
+    fn bytes_empty_groups() {
+        let policy = SeparatorPolicy {
+            groups: &[3, 0, 3],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_bytes(b"1234567", b",", policy),
+                    Ok(b"1,234,,567".to_vec()) );
+        assert_eq!( separate_bytes(b"1234567", b",", SeparatorPolicy {
+                        collapse_separators: true,
+                        .. policy
+                    }),
+                    Ok(b"1,234,567".to_vec()) );
+    }
+
+    #[test]
+    fn bytes_non_ascii_separator() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_bytes(b"1234", b"\xc2\xa0", policy),
+                    Err(NonAsciiSeparator { byte: 0xc2, index: 0 }) );
+        assert_eq!( separate_bytes(b"1234", b" \x80", policy),
+                    Err(NonAsciiSeparator { byte: 0x80, index: 1 }) );
+    }
+
+    #[test]
+    fn bytes_output_is_utf8() {
+        let input = "≈ 1234567 €".as_bytes();
+        let output = separate_bytes(input, b"'", policies::COMMA_SEPARATOR).unwrap();
+
+        assert_eq!( String::from_utf8(output).unwrap(), "≈ 1'234'567 €" );
+    }
+
+    #[test]
+    fn hex_bytes() {
+        assert_eq!( separate_hex_bytes(&[0xde, 0xad, 0xbe, 0xef], 4, " "),
+                    "dead beef" );
+        assert_eq!( separate_hex_bytes(&[0xde, 0xad, 0xbe, 0xef, 0x01], 4, " "),
+                    "dead beef 01" );
+        assert_eq!( separate_hex_bytes(&[0x0a, 0xb0], 1, "·"),
+                    "0·a·b·0" );
+    }
+
+    #[test]
+    fn hex_bytes_edge_cases() {
+        assert_eq!( separate_hex_bytes(&[], 4, " "),           "" );
+        assert_eq!( separate_hex_bytes(&[0xff], 4, " "),       "ff" );
+        assert_eq!( separate_hex_bytes(&[0xff, 0x00], 0, " "), "ff00" );
+    }
+}
@@ -0,0 +1,51 @@
+use super::{Separable, SeparatorPolicy};
+
+/// Adds separators, according to `policy`, to the numeric value of the code
+/// point of `c`.
+///
+/// Separating a `char` with [`Separable`] formats the character itself,
+/// since that’s what its `Display` implementation does, so this is needed
+/// to group its code point value instead.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{Separable, policies, separate_codepoint};
+///
+/// assert_eq!( separate_codepoint('💀', policies::COMMA_SEPARATOR), "128,128" );
+/// assert_eq!( '💀'.separate_with_commas(), "💀" );
+/// ```
+///
+/// [`Separable`]: trait.Separable.html
+pub fn separate_codepoint(c: char, policy: SeparatorPolicy) -> String {
+    (c as u32).separate_by_policy(policy)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{SeparatorPolicy, policies};
+    use super::*;
+
+    #[test]
+    fn decimal() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_codepoint('A', policy),           "65" );
+        assert_eq!( separate_codepoint('é', policy),           "233" );
+        assert_eq!( separate_codepoint('€', policy),           "8,364" );
+        assert_eq!( separate_codepoint('💀', policy),          "128,128" );
+        assert_eq!( separate_codepoint('\u{10FFFF}', policy),  "1,114,111" );
+    }
+
+    #[test]
+    fn other_policies() {
+        let policy = SeparatorPolicy {
+            separator: "_",
+            groups:    &[2],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_codepoint('💀', policy),  "12_81_28" );
+        assert_eq!( separate_codepoint('€', policies::SPACE_SEPARATOR),  "8 364" );
+    }
+}
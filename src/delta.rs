@@ -0,0 +1,105 @@
+use super::{Separable, SeparatorPolicy};
+use super::display::find_span;
+use super::exponent::split_fraction;
+
+/// Whether a number is positive, negative, or zero, as returned by
+/// [`Separable::separate_delta`](trait.Separable.html#method.separate_delta).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The number is greater than zero.
+    Positive,
+    /// The number is less than zero.
+    Negative,
+    /// The number is zero, or there is no number.
+    Zero,
+}
+
+/// Adds separators to `s` according to `policy`, with a `+` before the first
+/// number if it is positive and unsigned, and reports its sign.
+pub fn separate_delta(s: &str, policy: SeparatorPolicy) -> (String, Sign) {
+    let (before, number, after, _) =
+        find_span(s, |c| policy.is_digit(c), |c| policy.is_ignorable(c));
+
+    let (fraction, _) = split_fraction(after, policy.decimal_point, |c| policy.is_fraction_digit(c));
+    let zero          = policy.digits.first();
+    let is_zero       = number.chars().chain(fraction.chars().skip(1))
+                              .filter(|&c| policy.is_digit(c) || policy.is_fraction_digit(c))
+                              .all(|c| Some(&c) == zero);
+    if is_zero {
+        return (s.separate_by_policy(policy), Sign::Zero);
+    }
+
+    match before.chars().next_back() {
+        Some('-') | Some('\u{2212}') => (s.separate_by_policy(policy), Sign::Negative),
+        Some('+')                    => (s.separate_by_policy(policy), Sign::Positive),
+        _                            => {
+            // The sign goes on before formatting, so that the policy puts it
+            // where it would put a minus sign, such as after a currency code.
+            let signed = format!("{}+{}", before, &s[before.len() ..]);
+            (signed.separate_by_policy(policy), Sign::Positive)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{CodePlacement, Separable, SeparatorPolicy, policies};
+    use super::Sign;
+
+    fn delta(text: &str, sign: Sign) -> (String, Sign) {
+        (text.to_owned(), sign)
+    }
+
+    #[test]
+    fn positive() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1234.separate_delta(policy),             delta("+1,234", Sign::Positive) );
+        assert_eq!( 1234.5.separate_delta(policy),           delta("+1,234.5", Sign::Positive) );
+        assert_eq!( "+1234567".separate_delta(policy),       delta("+1,234,567", Sign::Positive) );
+        assert_eq!( "0.05".separate_delta(policy),           delta("+0.05", Sign::Positive) );
+        assert_eq!( "Δ 12345 %".separate_delta(policy),      delta("Δ +12,345 %", Sign::Positive) );
+    }
+
+    #[test]
+    fn currency_code_before() {
+        let policy = SeparatorPolicy {
+            iso_currency: Some(("EUR", CodePlacement::Before)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.separate_delta(policy),             delta("EUR +1,234", Sign::Positive) );
+        assert_eq!( (-1234.5).separate_delta(policy),        delta("EUR -1,234.5", Sign::Negative) );
+        assert_eq!( "Δ 12345".separate_delta(policy),        delta("Δ EUR +12,345", Sign::Positive) );
+    }
+
+    #[test]
+    fn negative() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-1234).separate_delta(policy),          delta("-1,234", Sign::Negative) );
+        assert_eq!( (-0.25).separate_delta(policy),          delta("-0.25", Sign::Negative) );
+        assert_eq!( "\u{2212}98765".separate_delta(policy),  delta("\u{2212}98,765", Sign::Negative) );
+    }
+
+    #[test]
+    fn zero() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.separate_delta(policy),                delta("0", Sign::Zero) );
+        assert_eq!( "0.000".separate_delta(policy),          delta("0.000", Sign::Zero) );
+        assert_eq!( "-0".separate_delta(policy),             delta("-0", Sign::Zero) );
+        assert_eq!( "n/a".separate_delta(policy),            delta("n/a", Sign::Zero) );
+    }
+
+    #[test]
+    fn other_digits() {
+        let policy = SeparatorPolicy {
+            regroup: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "12 34".separate_delta(policy),          delta("+1 234", Sign::Positive) );
+        assert_eq!( "0 000".separate_delta(policy),          delta("0 000", Sign::Zero) );
+    }
+}
@@ -1,3 +1,11 @@
+/// The binary digits, in ASCII.
+pub const ASCII_BINARY: &[char] = &['0', '1'];
+
+/// The octal digits, in ASCII.
+pub const ASCII_OCTAL: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7',
+];
+
 /// The decimal digits, in ASCII.
 pub const ASCII_DECIMAL: &[char] = &[
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
@@ -8,3 +16,29 @@ pub const ASCII_HEXADECIMAL: &[char] = &[
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
     'a', 'b', 'c', 'd', 'e', 'f', 'A', 'B', 'C', 'D', 'E', 'F',
 ];
+
+/// The decimal digits and the Latin letters, in ASCII, as used by base-36
+/// numbers.
+pub const ASCII_ALPHANUMERIC: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+];
+
+/// The base-32 alphabet of RFC 4648, not including the `=` padding.
+pub const BASE32: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+    '2', '3', '4', '5', '6', '7',
+];
+
+/// The base-64 alphabet of RFC 4648, not including the `=` padding.
+pub const BASE64: &[char] = &[
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '+', '/',
+];
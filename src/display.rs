@@ -1,39 +1,588 @@
 use std::fmt::Display;
 
-use super::{Separable, SeparatorPolicy};
+use super::{OnMalformed, SeparateError, Separable, SeparatorPolicy, UnexpectedChar, digits,
+            policies};
+use super::ascii::separate_ascii;
+use super::exponent::{Exponent, split_fraction};
 use super::helpers::SeparatorIterator;
+use super::stream::{Piece, keeps_after, power_of_ten_text, walk, with_formatted_after};
 
 impl Separable for str {
     fn separate_by_policy(&self, policy: SeparatorPolicy) -> String {
-        let (before, number, after, count) = find_span(&self, |c| policy.digits.contains(&c));
-        let iter = SeparatorIterator::new(&policy, count);
+        separate_with_capacity(self, policy, 0)
+    }
+}
+
+impl<T: Display> Separable for T {
+    fn separate_by_policy(&self, policy: SeparatorPolicy) -> String {
+        self.to_string().as_str().separate_by_policy(policy)
+    }
+}
+
+/// Adds separators to `s` according to `policy`, returning a string with
+/// room for at least `capacity` bytes.
+pub fn separate_with_capacity(s: &str, policy: SeparatorPolicy, capacity: usize) -> String {
+    if is_too_short(s, &policy) {
+        let mut result = String::with_capacity(capacity.max(s.len()));
+        result.push_str(s);
+        return result;
+    }
+
+    match separate_ascii(s, &policy, capacity) {
+        Some(result) => result,
+        None         => separate_chars(s, policy, capacity),
+    }
+}
+
+/// Whether `s` is certain to come out unchanged because its number is too
+/// short to separate. This may give `false` for some such strings.
+pub fn is_too_short(s: &str, policy: &SeparatorPolicy) -> bool {
+    if !policy.only_inserts_separators() || !keeps_after(policy) {
+        return false;
+    }
+
+    let mut count = 0;
+
+    for c in s.chars().skip_while(|&c| !policy.is_digit(c)) {
+        if policy.is_digit(c) {
+            count += 1;
+        } else if policy.is_ignorable(c) {
+            // Ignorable characters within the number are dropped.
+            return false;
+        } else {
+            break;
+        }
+    }
+
+    SeparatorIterator::new(policy, count).sep_len() == 0
+}
+
+/// Adds separators to `s` according to `policy` a character at a time.
+pub fn separate_chars(s: &str, policy: SeparatorPolicy, capacity: usize) -> String {
+    let separator_len = policy.separator.len() + policy.joiner().len();
+    let mut result    = String::with_capacity(capacity.max(s.len() + s.len() / 3 * separator_len));
+
+    walk(s, &policy, |piece| push_piece(&mut result, piece, &policy));
+
+    result
+}
+
+/// Adds separators to `s` according to `policy`, returning the result along
+/// with the number of characters in it before the text after the integer
+/// part, such as a fraction, so that numbers can be aligned on that point.
+pub fn separate_integer_len(s: &str, policy: SeparatorPolicy) -> (String, usize) {
+    let mut result  = String::with_capacity(s.len() + s.len() / 3 * policy.separator.len());
+    let mut int_len = None;
+
+    walk(s, &policy, |piece| {
+        if let Piece::After(_) | Piece::Rest(_) = piece {
+            int_len.get_or_insert_with(|| result.chars().count());
+        }
+        push_piece(&mut result, piece, &policy);
+    });
+
+    let int_len = int_len.unwrap_or_else(|| result.chars().count());
+    (result, int_len)
+}
+
+// Appends the text of one piece of a separated number.
+fn push_piece(result: &mut String, piece: Piece, policy: &SeparatorPolicy) {
+    match piece {
+        Piece::Before(text)   => result.push_str(text),
+        Piece::Digits(digits) => policy.push_digits(result, digits, |_| true),
+        Piece::Boundary(boundary) => {
+            result.push_str(policy.separator_at(boundary));
+            result.push_str(policy.joiner());
+        }
+        Piece::Elision      => result.push_str(policy.ellipsis()),
+        Piece::After(after) => push_after(result, after, policy),
+        Piece::PowerOfTen(exponent) => result.push_str(&power_of_ten_text(exponent)),
+        Piece::Currency     => result.push_str(policy.currency_code()),
+        Piece::Rest(text)   => result.push_str(text),
+    }
+}
 
-        let mut result = String::with_capacity(self.len() + iter.sep_len());
+/// Adds separators to `s` according to `policy`, returning the result with
+/// its characters in reverse order, except that each separator and ellipsis
+/// keeps its own order.
+pub fn separate_reversed(s: &str, policy: SeparatorPolicy) -> String {
+    let mut pieces = Vec::new();
+    walk(s, &policy, |piece| pieces.push(piece));
 
-        result.push_str(before);
+    let mut result = String::with_capacity(s.len() + s.len() / 3 * policy.separator.len());
 
-        for (digit, comma_after) in number.chars().zip(iter) {
-            result.push(digit);
-            if comma_after {
-                result.push_str(policy.separator);
+    for &piece in pieces.iter().rev() {
+        match piece {
+            Piece::Before(text)       => result.extend(text.chars().rev()),
+            Piece::Digits(digits)     => match policy.map_digits {
+                Some(map) => result.extend(digits.chars().rev().map(map)),
+                None      => result.extend(digits.chars().rev()),
+            },
+            // Separators are kept whole, so that one such as `<>` isn’t
+            // turned into `><`.
+            Piece::Boundary(boundary) => {
+                result.push_str(policy.joiner());
+                result.push_str(policy.separator_at(boundary));
             }
+            Piece::Elision            => result.push_str(policy.ellipsis()),
+            Piece::After(after)       =>
+                with_formatted_after(after, &policy, |text| result.extend(text.chars().rev())),
+            Piece::PowerOfTen(exponent) =>
+                result.extend(power_of_ten_text(exponent).chars().rev()),
+            Piece::Currency           => result.extend(policy.currency_code().chars().rev()),
+            Piece::Rest(text)         => result.extend(text.chars().rev()),
         }
+    }
 
-        result.push_str(after);
+    result
+}
 
-        result
+/// Adds separators to `s`, whose characters are in reverse order, according
+/// to `policy`, returning the result in reverse order too.
+pub fn separate_from_reversed(s: &str, policy: SeparatorPolicy) -> String {
+    separate_reversed(&s.chars().rev().collect::<String>(), policy)
+}
+
+/// Groups the digits of `s` according to `policy`, wrapping each group in
+/// brackets in place of separators.
+pub fn separate_debug(s: &str, policy: SeparatorPolicy) -> String {
+    let mut result   = String::with_capacity(s.len() * 2);
+    let mut in_group = false;
+
+    walk(s, &policy, |piece| match piece {
+        Piece::Before(text)   => result.push_str(text),
+        Piece::Digits(digits) => {
+            if !in_group {
+                result.push('[');
+                in_group = true;
+            }
+            policy.push_digits(&mut result, digits, |_| true);
+        }
+        Piece::Boundary(_)    => {
+            // Two boundaries in a row surround an empty group.
+            result.push_str(if in_group { "]" } else { "[]" });
+            in_group = false;
+        }
+        Piece::Elision        => {
+            result.push(']');
+            result.push_str(policy.ellipsis());
+            in_group = false;
+        }
+        Piece::After(after)   => {
+            if in_group {
+                result.push(']');
+                in_group = false;
+            }
+            push_after(&mut result, after, &policy);
+        }
+        Piece::PowerOfTen(exponent) => result.push_str(&power_of_ten_text(exponent)),
+        Piece::Currency       => result.push_str(policy.currency_code()),
+        Piece::Rest(text)     => {
+            if in_group {
+                result.push(']');
+                in_group = false;
+            }
+            result.push_str(text);
+        }
+    });
+
+    if in_group {
+        result.push(']');
     }
+
+    result
 }
 
-impl<T: Display> Separable for T {
-    fn separate_by_policy(&self, policy: SeparatorPolicy) -> String {
-        self.to_string().as_str().separate_by_policy(policy)
+/// Whether the text after the first run of digits makes the number malformed,
+/// by having a second decimal point followed by more digits.
+pub fn is_malformed(after: &str, policy: &SeparatorPolicy) -> bool {
+    let is_digit = |c| policy.is_fraction_digit(c);
+    let (fraction, rest) = split_fraction(after, policy.decimal_point, is_digit);
+
+    let mut rest = rest.chars();
+    !fraction.is_empty()
+        && rest.next() == Some(policy.decimal_point)
+        && match rest.next() {
+            Some(c) => is_digit(c),
+            None    => false,
+        }
+}
+
+/// Appends the text that follows the first run of digits.
+pub fn push_after(result: &mut String, after: &str, policy: &SeparatorPolicy) {
+    let (fraction, rest) =
+        split_fraction(after, policy.decimal_point, |c| policy.is_fraction_digit(c));
+    let fraction = match policy.truncate_fraction {
+        Some(0)      => "",
+        Some(places) => fraction.char_indices().nth(places + 1)
+                                .map_or(fraction, |(end, _)| &fraction[.. end]),
+        None         => fraction,
+    };
+    let fraction = match fraction.trim_end_matches('0') {
+        _ if !policy.trim_fraction_zeros => fraction,
+        // The decimal point stays when there is padding to follow it.
+        trimmed if trimmed.chars().count() <= 1 && policy.fraction_pad.is_none() => "",
+        trimmed => trimmed,
+    };
+
+    match policy.fractional_groups {
+        Some(groups) => push_fraction_groups(result, fraction, groups, policy),
+        None         => policy.push_digits(result, fraction, |c| policy.is_fraction_digit(c)),
+    }
+
+    if let Some((width, pad)) = policy.fraction_pad {
+        let padding = match fraction.chars().count() {
+            0 if width > 0 => width + 1,
+            0              => 0,
+            len            => width.saturating_sub(len - 1),
+        };
+
+        for _ in 0 .. padding {
+            result.push(pad);
+        }
+    }
+
+    if policy.superscript_exponent || policy.group_exponent {
+        if let Some(exponent) = Exponent::split(rest) {
+            if policy.superscript_exponent {
+                exponent.push_superscript(result);
+            } else {
+                push_grouped_exponent(result, &exponent, policy);
+            }
+            return;
+        }
+    }
+
+    result.push_str(rest);
+}
+
+// Appends `exponent` with separators added to its digits. Only the grouping
+// carries over from `policy`, since its other options apply to the number as
+// a whole and have already been applied to it.
+fn push_grouped_exponent(result: &mut String, exponent: &Exponent, policy: &SeparatorPolicy) {
+    let exponent_policy = SeparatorPolicy {
+        separator:       policy.separator,
+        groups:          policy.groups,
+        bind_with_zwj:   policy.bind_with_zwj,
+        separator_fn:    policy.separator_fn,
+        major_separator: policy.major_separator,
+        first_group:     policy.first_group,
+        .. policies::COMMA_SEPARATOR
+    };
+
+    result.push(exponent.marker);
+    result.push_str(exponent.sign);
+    result.push_str(&exponent.digits.separate_by_policy(exponent_policy));
+    result.push_str(exponent.rest);
+}
+
+// A policy that inserts no separators, so separating by it recovers the text
+// that a `Separable` would insert separators into.
+const UNSEPARATED: SeparatorPolicy<'static> = SeparatorPolicy {
+    groups: &[],
+    .. policies::COMMA_SEPARATOR
+};
+
+/// The text that `value` inserts separators into.
+pub fn text_of<S: Separable + ?Sized>(value: &S) -> String {
+    value.separate_by_policy(UNSEPARATED)
+}
+
+/// Quotes `cell` as a CSV field, per RFC 4180, if it contains a comma, a
+/// double quote, or a line break.
+pub fn csv_cell(cell: String) -> String {
+    if !cell.contains(&[',', '"', '\r', '\n'][..]) {
+        return cell;
+    }
+
+    let mut result = String::with_capacity(cell.len() + 2);
+
+    result.push('"');
+    for c in cell.chars() {
+        if c == '"' {
+            result.push('"');
+        }
+        result.push(c);
+    }
+    result.push('"');
+
+    result
+}
+
+/// Adds separators to `s` according to `policy`, dropping everything after
+/// the first run of digits.
+pub fn separate_integer_only(s: &str, policy: SeparatorPolicy) -> String {
+    let (_, _, after, _) = find_span(s, |c| policy.is_digit(c),
+                                     |c| policy.is_ignorable(c));
+
+    s[.. s.len() - after.len()].separate_by_policy(policy)
+}
+
+/// Adds separators to `s`, choosing the digits and grouping according to its
+/// radix prefix, if any, and taking everything else from `policy`.
+pub fn separate_auto(s: &str, policy: SeparatorPolicy) -> String {
+    let sign_len = match s.chars().next() {
+        Some(c) if is_sign(c) => c.len_utf8(),
+        _                     => 0,
+    };
+    let prefix   = s[sign_len ..].get(.. 2).map(str::to_ascii_lowercase);
+
+    let (digits, groups): (&[char], &[u8]) = match prefix.as_deref() {
+        Some("0x") => (digits::ASCII_HEXADECIMAL, &[4]),
+        Some("0b") => (digits::ASCII_BINARY,      &[4]),
+        Some("0o") => (digits::ASCII_OCTAL,       &[3]),
+        _          => return s.separate_by_policy(SeparatorPolicy {
+            digits: digits::ASCII_DECIMAL,
+            groups: &[3],
+            .. policy
+        }),
+    };
+
+    let (prefix, number) = s.split_at(sign_len + 2);
+    let mut result = prefix.to_owned();
+    result.push_str(&number.separate_by_policy(SeparatorPolicy { digits, groups, .. policy }));
+    result
+}
+
+/// Whether `c` is a sign that can come before a number: `-`, `+`, or the
+/// minus sign `−` (U+2212).
+pub fn is_sign(c: char) -> bool {
+    c == '-' || c == '+' || c == '\u{2212}'
+}
+
+/// Adds separators to `s` according to `policy`, unless `s` has no digits or
+/// the policy says to reject it as malformed.
+pub fn try_separate(s: &str, policy: SeparatorPolicy) -> Result<String, SeparateError> {
+    let (_, _, after, count) = find_span(s, |c| policy.is_digit(c),
+                                         |c| policy.is_ignorable(c));
+
+    if count == 0 {
+        Err(SeparateError::NoDigits)
+    } else if policy.on_malformed == OnMalformed::Error && is_malformed(after, &policy) {
+        Err(SeparateError::Malformed)
+    } else {
+        Ok(s.separate_by_policy(policy))
+    }
+}
+
+/// Inserts `separator` after each decimal digit of the first run of ASCII
+/// digits in `s` whose entry in `mask`, counting digits from the right, is
+/// `true`, except for the last digit.
+pub fn separate_with_mask(s: &str, separator: &str, mask: &[bool]) -> String {
+    let is_digit = |c| digits::ASCII_DECIMAL.contains(&c);
+    let (before, number, after, count) = find_span(s, is_digit, |_| false);
+    let sep_count = mask.iter().take(count).skip(1).filter(|&&b| b).count();
+
+    let mut result = String::with_capacity(s.len() + sep_count * separator.len());
+
+    result.push_str(before);
+
+    for (index, digit) in number.chars().enumerate() {
+        result.push(digit);
+        // There’s no separator after the last digit, whatever `mask[0]` says.
+        if index + 1 < count && mask.get(count - 1 - index) == Some(&true) {
+            result.push_str(separator);
+        }
+    }
+
+    result.push_str(after);
+
+    result
+}
+
+/// Formats `s`, a whole number of minor currency units, as an amount with
+/// `scale` digits after `policy`’s decimal point, grouping the integer part and
+/// wrapping it in the given currency symbols. Text without digits is
+/// returned as it is.
+pub fn separate_money(s: &str, scale: usize, policy: SeparatorPolicy,
+                      prefix: &str, suffix: &str) -> String {
+    let is_digit = |c| policy.is_digit(c);
+    let (before, number, after, count) = find_span(s, is_digit, |_| false);
+    if count == 0 {
+        return s.to_owned();
+    }
+
+    let zero = policy.digits.first().cloned().unwrap_or('0');
+
+    // The digits are split by counting characters, since those of some digit
+    // sets take more than one byte.
+    let split = match scale.checked_sub(1) {
+        None        => Some(number.len()),
+        Some(index) => number.char_indices().rev().nth(index).map(|(i, _)| i),
+    };
+    let (integer, fraction) = match split {
+        Some(i) => number.split_at(i),
+        None    => ("", number),
+    };
+
+    let mut buf = [0; 4];
+    let integer: &str = if integer.is_empty() { zero.encode_utf8(&mut buf) } else { integer };
+
+    let mut result = String::with_capacity(s.len() + prefix.len() + suffix.len() + 4);
+
+    result.push_str(before);
+    result.push_str(prefix);
+    result.push_str(&integer.separate_by_policy(policy));
+    if scale > 0 {
+        result.push(policy.decimal_point);
+        for _ in count .. scale {
+            result.push(zero);
+        }
+        result.push_str(fraction);
+    }
+    result.push_str(suffix);
+    result.push_str(after);
+
+    result
+}
+
+/// Adds separators to `s` according to `policy`, unless the first run of
+/// digits is interrupted by letters or other alphanumeric characters that
+/// aren’t digits and are followed by more digits, as in `123OO456`. The
+/// error reports the first such character. An exponent such as `e56` is
+/// allowed there, and a unit such as `km` isn’t followed by digits.
+pub fn separate_strict(s: &str, policy: SeparatorPolicy) -> Result<String, UnexpectedChar> {
+    let is_digit = |c| policy.is_digit(c);
+    let (before, number, after, _) = find_span(s, is_digit, |c| policy.is_ignorable(c));
+
+    let run = match after.find(|c: char| !c.is_alphanumeric() && !is_digit(c)) {
+        Some(end) => &after[.. end],
+        None      => after,
+    };
+
+    let mut offset = 0;
+    if run.starts_with(&['e', 'E'][..]) {
+        let exponent = run[1 ..].find(|c| !is_digit(c)).unwrap_or(run.len() - 1);
+        if exponent > 0 {
+            offset = 1 + exponent;
+        }
+    }
+
+    let rest = &run[offset ..];
+    if rest.chars().any(is_digit) {
+        if let Some((i, ch)) = rest.char_indices().find(|&(_, c)| !is_digit(c)) {
+            return Err(UnexpectedChar { ch, index: before.len() + number.len() + offset + i });
+        }
+    }
+
+    Ok(s.separate_by_policy(policy))
+}
+
+/// Adds separators to `s` according to `policy` if its first number is in
+/// scientific notation, and otherwise returns it unchanged.
+pub fn separate_mantissa(s: &str, policy: SeparatorPolicy) -> String {
+    let (_, _, after, count) =
+        find_span(s, |c| policy.is_digit(c), |c| policy.is_ignorable(c));
+    let (_, rest) = split_fraction(after, policy.decimal_point, |c| policy.is_fraction_digit(c));
+
+    if count > 0 && Exponent::split(rest).is_some() {
+        s.separate_by_policy(policy)
+    } else {
+        s.to_owned()
+    }
+}
+
+/// Writes the first run of decimal digits in `s` with `words[i]` after the
+/// group of digits `i + 1` groups of three from the right, leaving out
+/// groups that are all zeros and the leading zeros of the others.
+pub fn scale_words(s: &str, words: &[&str]) -> String {
+    let (before, number, after, _) =
+        find_span(s, |c| digits::ASCII_DECIMAL.contains(&c), |_| false);
+
+    // Leading zeros would end up in the top group, so they go, though a
+    // number that is all zeros keeps one.
+    let number = match number.trim_start_matches('0') {
+        "" if !number.is_empty() => "0",
+        trimmed                  => trimmed,
+    };
+    let count  = number.len();
+
+    let scales = words.len().min(count.saturating_sub(1) / 3);
+    let (top, mut rest) = number.split_at(count - 3 * scales);
+
+    let words_len: usize = words[.. scales].iter().map(|word| word.len() + 2).sum();
+    let mut result = String::with_capacity(s.len() + words_len);
+
+    result.push_str(before);
+    result.push_str(top);
+    if scales > 0 {
+        result.push(' ');
+        result.push_str(words[scales - 1]);
+    }
+
+    for scale in (0 .. scales).rev() {
+        let (group, remaining) = rest.split_at(3);
+        rest = remaining;
+
+        let group = group.trim_start_matches('0');
+        if group.is_empty() {
+            continue;
+        }
+
+        result.push(' ');
+        result.push_str(group);
+        if scale > 0 {
+            result.push(' ');
+            result.push_str(words[scale - 1]);
+        }
+    }
+
+    result.push_str(after);
+
+    result
+}
+
+/// Puts `symbol` before `amount`, with enough spaces between them to make
+/// the result `width` characters wide.
+pub fn currency_aligned(symbol: &str, width: usize, amount: &str) -> String {
+    let used = symbol.chars().count() + amount.chars().count();
+    let pad  = width.saturating_sub(used);
+
+    let mut result = String::with_capacity(symbol.len() + pad + amount.len());
+
+    result.push_str(symbol);
+    for _ in 0 .. pad {
+        result.push(' ');
+    }
+    result.push_str(amount);
+
+    result
+}
+
+// Appends `fraction`, a decimal point followed by digits, to `result`,
+// grouping the digits from the point outward according to `groups`.
+fn push_fraction_groups(result: &mut String, fraction: &str, groups: &[u8],
+                        policy: &SeparatorPolicy) {
+    let is_digit  = |c| policy.is_fraction_digit(c);
+    let point_len = fraction.chars().next().map_or(0, char::len_utf8);
+    result.push_str(&fraction[.. point_len]);
+
+    let mut index    = 0;
+    let mut in_group = 0;
+
+    for c in fraction[point_len ..].chars() {
+        // The last group size repeats, and a zero ends the grouping.
+        let size = groups.get(index).or_else(|| groups.last()).map_or(0, |&size| size as usize);
+        if size > 0 && in_group == size {
+            result.push_str(policy.separator_at(0));
+            result.push_str(policy.joiner());
+            index   += 1;
+            in_group = 0;
+        }
+
+        policy.push_digits(result, c.encode_utf8(&mut [0; 4]), is_digit);
+        in_group += 1;
     }
 }
 
-fn find_span<F: Fn(char) -> bool>(s: &str, is_digit: F) -> (&str, &str, &str, usize) {
+/// Splits `s` into the text before its first run of digits, the run itself,
+/// and the text after, along with the number of digits in the run.
+pub fn find_span<F, G>(s: &str, is_digit: F, is_ignorable: G) -> (&str, &str, &str, usize)
+where F: Fn(char) -> bool,
+      G: Fn(char) -> bool {
+
     let start        = len_not_matching(s, &is_digit);
-    let (len, count) = len_and_count_matching(&s[start ..], &is_digit);
+    let (len, count) = len_and_count_matching(&s[start ..], &is_digit, is_ignorable);
     let limit        = start + len;
 
     (&s[.. start], &s[start .. limit], &s[limit ..], count)
@@ -49,24 +598,34 @@ where F: FnMut(char) -> bool {
     }
 }
 
-fn len_and_count_matching<F>(s: &str, pred: F) -> (usize, usize)
-where F: Fn(char) -> bool {
+// Returns the length of the longest prefix of `s` made of matching and
+// ignorable characters that ends with a matching character, along with the
+// number of matching characters in it.
+fn len_and_count_matching<F, G>(s: &str, pred: F, is_ignorable: G) -> (usize, usize)
+where F: Fn(char) -> bool,
+      G: Fn(char) -> bool {
 
     let mut count = 0;
-    let     len = len_not_matching(s, |c|
+    let mut len   = 0;
+
+    for (i, c) in s.char_indices() {
         if pred(c) {
             count += 1;
-            false
-        } else {
-            true
-        });
+            len    = i + c.len_utf8();
+        } else if !is_ignorable(c) {
+            break;
+        }
+    }
 
     (len, count)
 }
 
 #[cfg(test)]
 mod test {
-    use super::super::{Separable, SeparatorPolicy, digits, policies};
+    use super::super::{CodePlacement, OnMalformed, SeparateError, Separable, SeparatorPolicy,
+                       UnexpectedChar, digits, policies, separate_in_place};
+    use super::super::helpers::SeparatorIterator;
+    use super::{find_span, is_too_short, separate_chars};
 
     #[test]
     fn integer_thousands_commas() {
@@ -80,18 +639,86 @@ mod test {
             separator: "😃😃",
             groups:    &[1],
             digits:    &['🙁'],
+            .. policies::COMMA_SEPARATOR
         };
 
         assert_eq!( "  🙁🙁🙁🙁🙁  ".separate_by_policy(policy),
                     "  🙁😃😃🙁😃😃🙁😃😃🙁😃😃🙁  " );
     }
 
+    #[test]
+    fn separator_char_matches_str() {
+        for &(c, s) in &[(',', ","), ('.', "."), ('\u{202F}', "\u{202F}"), ('😃', "😃")] {
+            let mut buf = [0; 4];
+            let from_char = SeparatorPolicy::separator_char(c, &mut buf);
+            let from_str  = SeparatorPolicy { separator: s, .. policies::COMMA_SEPARATOR };
+
+            for value in &["1234567", "-98765.4321", "x=12345 m", "12", ""] {
+                assert_eq!( value.separate_by_policy(from_char), value.separate_by_policy(from_str) );
+            }
+        }
+    }
+
+    #[test]
+    fn multi_byte_separator_and_digits() {
+        // A narrow no-break space followed by a thin space, between
+        // Arabic-Indic digits, which take two bytes each.
+        let policy = SeparatorPolicy {
+            separator: "\u{202F}\u{2009}",
+            digits:    &['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+            .. policies::COMMA_SEPARATOR
+        };
+        let grouped = "١\u{202F}\u{2009}٢٣٤\u{202F}\u{2009}٥٦٧";
+
+        assert_eq!( "١٢٣٤٥٦٧".separate_by_policy(policy),           grouped );
+        assert_eq!( (&"١٢٣٤٥٦٧").separated(policy).to_string(),     grouped );
+        assert_eq!( "١٢٣٤٥٦٧".separate_reversed(policy),
+                    "٧٦٥\u{202F}\u{2009}٤٣٢\u{202F}\u{2009}١" );
+
+        let mut in_place = "١٢٣٤٥٦٧".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, grouped );
+
+        for &capacity in &[0, 1, 7, 100] {
+            assert_eq!( "١٢٣٤٥٦٧".separate_by_policy_with_capacity(policy, capacity), grouped );
+        }
+
+        let long: String = "٩".repeat(1000);
+        let result = long.separate_by_policy(policy);
+        assert_eq!( result.chars().filter(|&c| c == '٩').count(), 1000 );
+        assert_eq!( result.len(), 1000 * 2 + 333 * 6 );
+    }
+
+    #[test]
+    fn multibyte_surroundings() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( find_span("≈ 1234567 items", |c| c.is_ascii_digit(), |_| false),
+                    ("≈ ", "1234567", " items", 7) );
+        assert_eq!( find_span("≈1234567≈", |c| c.is_ascii_digit(), |_| false),
+                    ("≈", "1234567", "≈", 7) );
+        assert_eq!( find_span("€€€", |c| c.is_ascii_digit(), |_| false),
+                    ("€€€", "", "", 0) );
+
+        assert_eq!( "≈ 1234567 items".separate_by_policy(policy),    "≈ 1,234,567 items" );
+        assert_eq!( "≈1234567≈".separate_by_policy(policy),          "≈1,234,567≈" );
+        assert_eq!( "約1234567個".separate_by_policy(policy),         "約1,234,567個" );
+        assert_eq!( "𝑥 = 1234.5 €".separate_by_policy(policy),       "𝑥 = 1,234.5 €" );
+        assert_eq!( (&"≈ 1234567 items").separated(policy).to_string(),
+                    "≈ 1,234,567 items" );
+
+        let mut s = "≈ 1234567 items".to_owned();
+        separate_in_place(&mut s, policy);
+        assert_eq!( s, "≈ 1,234,567 items" );
+    }
+
     #[test]
     fn three_two_two_two() {
         let policy = SeparatorPolicy {
             separator: ",",
             groups:    &[3, 2],
-            digits:    &digits::ASCII_DECIMAL,
+            digits:    digits::ASCII_DECIMAL,
+            .. policies::COMMA_SEPARATOR
         };
 
         assert_eq!( "1234567890".separate_by_policy(policy),
@@ -109,4 +736,2007 @@ mod test {
         assert_eq!( "deadbeef".separate_by_policy(policies::HEX_FOUR),
                     "dead beef" );
     }
+
+    #[test]
+    fn base36() {
+        let policy = SeparatorPolicy {
+            separator: "-",
+            groups:    &[4],
+            digits:    digits::ASCII_ALPHANUMERIC,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "3w5e11264sgsg".separate_by_policy(policy),
+                    "3-w5e1-1264-sgsg" );
+        assert_eq!( "+ZIK0ZJ".separate_by_policy(policy),
+                    "+ZI-K0ZJ" );
+    }
+
+    #[test]
+    fn base32_and_base64() {
+        let base32 = SeparatorPolicy {
+            separator: " ",
+            groups:    &[4],
+            digits:    digits::BASE32,
+            .. policies::COMMA_SEPARATOR
+        };
+        let base64 = SeparatorPolicy {
+            digits:    digits::BASE64,
+            .. base32
+        };
+
+        assert_eq!( "MZXW6YTBOI======".separate_by_policy(base32),
+                    "MZ XW6Y TBOI======" );
+        assert_eq!( "Zm9v+/Ym==".separate_by_policy(base64),
+                    "Zm9v +/Ym==" );
+    }
+
+    #[test]
+    fn shared_results() {
+        let policy = policies::COMMA_SEPARATOR;
+        let owned  = "-1234567.5".separate_by_policy(policy);
+
+        assert_eq!( &*"-1234567.5".separate_arc(policy), owned.as_str() );
+        assert_eq!( &*"-1234567.5".separate_rc(policy),  owned.as_str() );
+        assert_eq!( &*1234567.separate_arc(policy),      "1,234,567" );
+    }
+
+    #[test]
+    fn pasted_no_break_spaces() {
+        let policy = SeparatorPolicy {
+            ignorable: &['\u{A0}', '\u{202F}'],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1\u{A0}234\u{A0}567".separate_by_policy(policy),
+                    "1,234,567" );
+        assert_eq!( "-12\u{202F}345\u{A0}678.9".separate_by_policy(policy),
+                    "-12,345,678.9" );
+        assert_eq!( "\u{A0}1234\u{A0}".separate_by_policy(policy),
+                    "\u{A0}1,234\u{A0}" );
+        assert_eq!( "1\u{A0}234\u{A0}567".separate_with_commas(),
+                    "1\u{A0}234\u{A0}567" );
+    }
+
+    #[test]
+    fn whole_result_case() {
+        assert_eq!( "No. 1234AbCdEf".separate_upper(policies::HEX_FOUR),
+                    "NO. 12 34AB CDEF" );
+        assert_eq!( "No. 1234AbCdEf".separate_lower(policies::HEX_FOUR),
+                    "no. 12 34ab cdef" );
+        assert_eq!( "Total: 12345".separate_upper(policies::COMMA_SEPARATOR),
+                    "TOTAL: 12,345" );
+    }
+
+    #[test]
+    fn irregular_mask() {
+        let mask = [false, true, false, false, true, true];
+
+        assert_eq!( "1234567".separate_with_mask(",", &mask),
+                    "12,3,456,7" );
+        assert_eq!( "-1234567.89".separate_with_mask("_", &mask),
+                    "-12_3_456_7.89" );
+        assert_eq!( 42.separate_with_mask(",", &mask),
+                    "4,2" );
+        assert_eq!( 1234567.separate_with_mask(",", &[]),
+                    "1234567" );
+    }
+
+    #[test]
+    fn mask_ignores_last_digit() {
+        assert_eq!( "1234567".separate_with_mask(",", &[true, false, true]),
+                    "12345,67" );
+        assert_eq!( "7 days".separate_with_mask(",", &[true]),
+                    "7 days" );
+        assert_eq!( "-12.5".separate_with_mask(",", &[true, true]),
+                    "-1,2.5" );
+    }
+
+    #[test]
+    fn mask_only_ascii_digits() {
+        assert_eq!( "١٢٣٤".separate_with_mask(",", &[false, false, false, true]),
+                    "١٢٣٤" );
+        assert_eq!( "x١٢٣٤ 5678".separate_with_mask(",", &[false, false, false, true]),
+                    "x١٢٣٤ 5,678" );
+    }
+
+    #[test]
+    fn mask_from_policy() {
+        let policy = policies::COMMA_SEPARATOR;
+        let mut mask: Vec<bool> = SeparatorIterator::new(&policy, 10).collect();
+        mask.reverse();
+
+        assert_eq!( 1234567890.separate_with_mask(",", &mask),
+                    1234567890.separate_by_policy(policy) );
+    }
+
+    #[test]
+    fn money() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 123456.separate_money(2, policy, "$", ""),     "$1,234.56" );
+        assert_eq!( 123456789.separate_money(2, policy, "", " USD"),
+                    "1,234,567.89 USD" );
+        assert_eq!( 123456.separate_money(0, policy, "¥", ""),     "¥123,456" );
+        assert_eq!( 123456.separate_money(3, policy, "", " KWD"),  "123.456 KWD" );
+    }
+
+    #[test]
+    fn money_negative() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-123456).separate_money(2, policy, "$", ""),  "-$1,234.56" );
+        assert_eq!( (-5).separate_money(2, policy, "$", ""),       "-$0.05" );
+        assert_eq!( "\u{2212}123456".separate_money(2, policy, "$", ""),
+                    "\u{2212}$1,234.56" );
+    }
+
+    #[test]
+    fn money_smaller_than_scale() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.separate_money(2, policy, "$", ""),          "$0.00" );
+        assert_eq!( 5.separate_money(2, policy, "$", ""),          "$0.05" );
+        assert_eq!( 42.separate_money(2, policy, "$", ""),         "$0.42" );
+        assert_eq!( 123.separate_money(2, policy, "$", ""),        "$1.23" );
+        assert_eq!( 7.separate_money(4, policy, "", " BTC"),       "0.0007 BTC" );
+    }
+
+    #[test]
+    fn money_without_digits() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "abc".separate_money(2, policy, "$", ""),      "abc" );
+        assert_eq!( "".separate_money(2, policy, "", " EUR"),      "" );
+        assert_eq!( "-".separate_money(0, policy, "$", ""),        "-" );
+    }
+
+    #[test]
+    fn money_other_digits() {
+        let devanagari = SeparatorPolicy {
+            digits: &['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
+            .. policies::COMMA_SEPARATOR
+        };
+        let arabic_indic = SeparatorPolicy {
+            digits: &['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "१२३४५".separate_money(2, devanagari, "₹", ""),     "₹१२३.४५" );
+        assert_eq!( "१२३४५६७".separate_money(2, devanagari, "₹", ""),   "₹१२,३४५.६७" );
+        assert_eq!( "١٢٣٤٥".separate_money(2, arabic_indic, "", ""),    "١٢٣.٤٥" );
+        assert_eq!( "٥".separate_money(2, arabic_indic, "", ""),        "٠.٠٥" );
+    }
+
+    #[test]
+    fn superscript_exponent() {
+        let policy = SeparatorPolicy {
+            superscript_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e3".separate_by_policy(policy),      "1.5 × 10³" );
+        assert_eq!( "-2.25E-10".separate_by_policy(policy),  "-2.25 × 10⁻¹⁰" );
+        assert_eq!( "12345e+6".separate_by_policy(policy),   "12,345 × 10⁶" );
+        assert_eq!( 1234.5e3.separate_by_policy(policy),     "1,234,500" );
+        assert_eq!( format!("{:e}", 1500.0).separate_by_policy(policy),
+                    "1.5 × 10³" );
+        assert_eq!( "1.5e3".separate_with_commas(),          "1.5e3" );
+    }
+
+    #[test]
+    fn dangling_exponent() {
+        let superscript = SeparatorPolicy {
+            superscript_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        for &policy in &[policies::COMMA_SEPARATOR, superscript] {
+            assert_eq!( "1234567e".separate_by_policy(policy),     "1,234,567e" );
+            assert_eq!( "1234567E+".separate_by_policy(policy),    "1,234,567E+" );
+            assert_eq!( "1234567e-".separate_by_policy(policy),    "1,234,567e-" );
+            assert_eq!( "1234567.5e".separate_by_policy(policy),   "1,234,567.5e" );
+            assert_eq!( "1234567p".separate_by_policy(policy),     "1,234,567p" );
+            assert_eq!( "1234567e- m".separate_by_policy(policy),  "1,234,567e- m" );
+        }
+    }
+
+    // A fixed-capacity string, like `heapless::String<N>`.
+    struct FixedString {
+        buffer: [u8; 32],
+        len:    usize,
+    }
+
+    impl FixedString {
+        fn new() -> Self {
+            FixedString { buffer: [0; 32], len: 0 }
+        }
+
+        fn as_str(&self) -> &str {
+            ::std::str::from_utf8(&self.buffer[.. self.len]).unwrap()
+        }
+    }
+
+    impl ::std::fmt::Write for FixedString {
+        fn write_str(&mut self, s: &str) -> ::std::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buffer.len() {
+                return Err(::std::fmt::Error);
+            }
+
+            self.buffer[self.len .. end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn into_fixed_capacity() {
+        let mut buffer = FixedString::new();
+
+        assert!( 1234567890u64.separate_into(policies::COMMA_SEPARATOR, &mut buffer).is_ok() );
+        assert_eq!( buffer.as_str(), "1,234,567,890" );
+    }
+
+    #[test]
+    fn into_fixed_capacity_exceeded() {
+        let mut buffer = FixedString::new();
+
+        assert!( u128::MAX.separate_into(policies::COMMA_SEPARATOR, &mut buffer).is_err() );
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn into_heapless() {
+        let policy = policies::COMMA_SEPARATOR;
+        let result: ::heapless::String<32> = (-1234567.5).separate_heapless(policy).unwrap();
+
+        assert_eq!( result, "-1,234,567.5" );
+        assert_eq!( u128::MAX.separate_heapless::<32>(policy), Err(()) );
+        assert_eq!( u128::MAX.separate_heapless::<64>(policy).unwrap(),
+                    "340,282,366,920,938,463,463,374,607,431,768,211,455" );
+    }
+
+    #[test]
+    fn try_separate() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "abc".try_separate(policy),     Err(SeparateError::NoDigits) );
+        assert_eq!( "".try_separate(policy),        Err(SeparateError::NoDigits) );
+        assert_eq!( "a1b".try_separate(policy),     Ok("a1b".to_owned()) );
+        assert_eq!( "-12345".try_separate(policy),  Ok("-12,345".to_owned()) );
+        assert_eq!( 0.try_separate(policy),         Ok("0".to_owned()) );
+    }
+
+    #[test]
+    fn csv_cells() {
+        assert_eq!( 1234567.separate_csv_cell(policies::COMMA_SEPARATOR),
+                    "\"1,234,567\"" );
+        assert_eq!( 1234567.separate_csv_cell(policies::SPACE_SEPARATOR),
+                    "1 234 567" );
+        assert_eq!( 123.separate_csv_cell(policies::COMMA_SEPARATOR),
+                    "123" );
+        assert_eq!( "12345 \"units\"".separate_csv_cell(policies::DOT_SEPARATOR),
+                    "\"12.345 \"\"units\"\"\"" );
+    }
+
+    #[test]
+    fn currency_aligned_column() {
+        let policy  = policies::COMMA_SEPARATOR;
+        let amounts = ["1234567.89", "1234.00", "5.50", "-987654.32", "0.00"];
+        let column: Vec<String> = amounts.iter()
+            .map(|amount| amount.separate_currency_aligned("$", 14, policy))
+            .collect();
+
+        assert_eq!( column, [
+            "$ 1,234,567.89",
+            "$     1,234.00",
+            "$         5.50",
+            "$  -987,654.32",
+            "$         0.00",
+        ] );
+    }
+
+    #[test]
+    fn currency_aligned_edges() {
+        let policy = policies::SPACE_SEPARATOR;
+
+        assert_eq!( 1234567.separate_currency_aligned("€", 12, policy),
+                    "€  1 234 567" );
+        assert_eq!( 1234567.separate_currency_aligned("CHF ", 6, policy),
+                    "CHF 1 234 567" );
+        assert_eq!( 12.separate_currency_aligned("", 5, policy),
+                    "   12" );
+    }
+
+    #[test]
+    fn utf16() {
+        let thin = SeparatorPolicy {
+            separator: "\u{2009}",
+            .. policies::COMMA_SEPARATOR
+        };
+        let emoji = SeparatorPolicy {
+            separator: "\u{1F4B0}",
+            .. policies::COMMA_SEPARATOR
+        };
+
+        for &policy in &[policies::COMMA_SEPARATOR, thin, emoji] {
+            let wide = (-1234567.5).separate_utf16(policy);
+            assert_eq!( String::from_utf16(&wide).unwrap(),
+                        (-1234567.5).separate_by_policy(policy) );
+        }
+
+        assert_eq!( 1234.separate_utf16(thin),
+                    [0x31, 0x2009, 0x32, 0x33, 0x34] );
+        assert_eq!( 1234.separate_utf16(emoji),
+                    [0x31, 0xD83D, 0xDCB0, 0x32, 0x33, 0x34] );
+    }
+
+    #[test]
+    fn with_capacity() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        for &capacity in &[0, 1, 9, 64, 1000] {
+            let result = 1234567.separate_by_policy_with_capacity(policy, capacity);
+            assert_eq!( result, "1,234,567" );
+            assert!( result.capacity() >= capacity );
+        }
+
+        let malformed = SeparatorPolicy {
+            on_malformed: OnMalformed::Passthrough,
+            .. policy
+        };
+        let result = "1.2.3".separate_by_policy_with_capacity(malformed, 40);
+        assert_eq!( result, "1.2.3" );
+        assert!( result.capacity() >= 40 );
+    }
+
+    #[test]
+    fn scale_words() {
+        let words = &["thousand", "million", "billion"];
+
+        assert_eq!( 1234567.separate_scale_words(words),
+                    "1 million 234 thousand 567" );
+        assert_eq!( 1000000.separate_scale_words(words),
+                    "1 million" );
+        assert_eq!( 1002003.separate_scale_words(words),
+                    "1 million 2 thousand 3" );
+        assert_eq!( (-12345).separate_scale_words(words),
+                    "-12 thousand 345" );
+        assert_eq!( 999.separate_scale_words(words),
+                    "999" );
+        assert_eq!( 0.separate_scale_words(words),
+                    "0" );
+        assert_eq!( 1234567.separate_scale_words(&["thousand"]),
+                    "1234 thousand 567" );
+        assert_eq!( 1234567.separate_scale_words(&[]),
+                    "1234567" );
+        assert_eq!( "about 1500 people".separate_scale_words(words),
+                    "about 1 thousand 500 people" );
+    }
+
+    #[test]
+    fn scale_words_leading_zeros() {
+        let words = &["thousand", "million", "billion"];
+
+        assert_eq!( "0001234".separate_scale_words(words),
+                    "1 thousand 234" );
+        assert_eq!( "id 000000".separate_scale_words(words),
+                    "id 0" );
+        assert_eq!( "-01000000".separate_scale_words(words),
+                    "-1 million" );
+    }
+
+    #[test]
+    fn nanoseconds() {
+        use std::time::Duration;
+
+        let uptime = Duration::new(86_400 * 365, 123_456_789);
+        assert_eq!( uptime.as_nanos().separate_with_commas(),
+                    "31,536,000,123,456,789" );
+
+        assert_eq!( i64::MAX.separate_with_commas(),
+                    "9,223,372,036,854,775,807" );
+        assert_eq!( i64::MIN.separate_with_commas(),
+                    "-9,223,372,036,854,775,808" );
+        assert_eq!( u64::MAX.separate_with_commas(),
+                    "18,446,744,073,709,551,615" );
+        assert_eq!( u128::MAX.separate_with_commas(),
+                    "340,282,366,920,938,463,463,374,607,431,768,211,455" );
+        assert_eq!( i128::MIN.separate_with_commas(),
+                    "-170,141,183,460,469,231,731,687,303,715,884,105,728" );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn pointer_sized_64() {
+        assert_eq!( usize::MAX.separate_with_commas(),   "18,446,744,073,709,551,615" );
+        assert_eq!( isize::MIN.separate_with_commas(),   "-9,223,372,036,854,775,808" );
+        assert_eq!( usize::MAX.separate_by_policy(SeparatorPolicy {
+                        groups: &[3, 2],
+                        .. policies::COMMA_SEPARATOR
+                    }),
+                    "1,84,46,74,40,73,70,95,51,615" );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "32")]
+    fn pointer_sized_32() {
+        assert_eq!( usize::MAX.separate_with_commas(),   "4,294,967,295" );
+        assert_eq!( isize::MIN.separate_with_commas(),   "-2,147,483,648" );
+        assert_eq!( usize::MAX.separate_by_policy(SeparatorPolicy {
+                        groups: &[3, 2],
+                        .. policies::COMMA_SEPARATOR
+                    }),
+                    "4,29,49,67,295" );
+    }
+
+    #[test]
+    fn pointer_sized_any_width() {
+        for &value in &[usize::MAX, usize::MAX / 2, !(usize::MAX >> 1)] {
+            let digits = value.to_string();
+            let separators = (digits.len() - 1) / 3;
+
+            assert_eq!( value.separate_with_commas().len(), digits.len() + separators );
+            assert_eq!( value.separate_with_commas().replace(',', ""), digits );
+
+            let mut in_place = digits.clone();
+            separate_in_place(&mut in_place, policies::COMMA_SEPARATOR);
+            assert_eq!( in_place, value.separate_with_commas() );
+        }
+
+        for &value in &[isize::MIN, isize::MAX] {
+            assert_eq!( value.separate_with_spaces().replace(' ', ""), value.to_string() );
+        }
+    }
+
+    #[test]
+    fn fraction_digits() {
+        const FULLWIDTH: &[char] = &[
+            '０', '１', '２', '３', '４', '５', '６', '７', '８', '９',
+        ];
+
+        let policy = SeparatorPolicy {
+            fraction_digits: Some(FULLWIDTH),
+            fraction_pad:    Some((4, '_')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1234567.８９".separate_by_policy(policy),  "1,234,567.８９__" );
+        assert_eq!( "1234567.89".separate_by_policy(policy),    "1,234,567.____89" );
+        assert_eq!( "１２３４.５".separate_by_policy(policy),      "１２３４.５" );
+
+        let ascii = SeparatorPolicy {
+            fraction_digits: None,
+            .. policy
+        };
+
+        assert_eq!( "1234567.８９".separate_by_policy(ascii),   "1,234,567.____８９" );
+
+        let strict = SeparatorPolicy {
+            on_malformed: OnMalformed::Error,
+            .. policy
+        };
+
+        assert_eq!( "1234.５.６".try_separate(strict),          Err(SeparateError::Malformed) );
+        assert_eq!( "1234.5.6".try_separate(strict),            Ok("1,234.____5.6".to_owned()) );
+    }
+
+    #[test]
+    fn bind_with_zwj() {
+        let policy = SeparatorPolicy {
+            bind_with_zwj: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567.separate_by_policy(policy),  "1,\u{200D}234,\u{200D}567" );
+        assert_eq!( 123.separate_by_policy(policy),      "123" );
+
+        let several = SeparatorPolicy {
+            separators_after: &[(1, " ")],
+            .. policy
+        };
+
+        assert_eq!( 1234567.separate_by_policy(several), "1 \u{200D}234,\u{200D}567" );
+    }
+
+    #[test]
+    fn regroup() {
+        let policy = SeparatorPolicy {
+            regroup: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1,23,456".separate_by_policy(policy),         "123,456" );
+        assert_eq!( "12,34,56,789.5".separate_by_policy(policy),   "123,456,789.5" );
+        assert_eq!( "1,,2345".separate_by_policy(policy),          "12,345" );
+        assert_eq!( "1,234,567".separate_by_policy(policy),        "1,234,567" );
+        assert_eq!( "1234567,".separate_by_policy(policy),         "1,234,567," );
+        assert_eq!( "1,23,456".separate_with_commas(),             "1,23,456" );
+
+        let indian = SeparatorPolicy {
+            groups: &[3, 2],
+            .. policy
+        };
+
+        assert_eq!( "123,456,789".separate_by_policy(indian),      "12,34,56,789" );
+
+        let several = SeparatorPolicy {
+            separators_after: &[(1, " ")],
+            .. policy
+        };
+
+        assert_eq!( "1,2 34 567".separate_by_policy(several),      "1 234,567" );
+    }
+
+    #[test]
+    fn major_separator() {
+        let policy = SeparatorPolicy {
+            separator:       ".",
+            major_separator: Some(("′", 3)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 123456789012u64.separate_by_policy(policy),   "123′456.789.012" );
+        assert_eq!( 1234567890123456789u64.separate_by_policy(policy),
+                    "1′234.567.890′123.456.789" );
+        assert_eq!( 123456789.separate_by_policy(policy),         "123.456.789" );
+
+        let every_other = SeparatorPolicy {
+            major_separator: Some((" ", 2)),
+            separators_after: &[(0, "_")],
+            .. policy
+        };
+
+        assert_eq!( 123456789012u64.separate_by_policy(every_other),
+                    "123_456 789_012" );
+
+        let never = SeparatorPolicy {
+            major_separator: Some((" ", 0)),
+            .. policy
+        };
+
+        assert_eq!( 123456789012u64.separate_by_policy(never),    "123.456.789.012" );
+    }
+
+    #[test]
+    fn separator_fn() {
+        fn millions_marker(boundary: usize) -> &'static str {
+            match boundary {
+                1 => "ᴍ",
+                _ => ",",
+            }
+        }
+
+        let policy = SeparatorPolicy {
+            separator_fn:    Some(millions_marker),
+            major_separator: Some((" ", 1)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567890.separate_by_policy(policy),  "1,234ᴍ567,890" );
+        assert_eq!( 1234567.separate_by_policy(policy),     "1ᴍ234,567" );
+        assert_eq!( 1234.separate_by_policy(policy),        "1,234" );
+
+        let mut in_place = String::from("1234567");
+        ::separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "1ᴍ234,567" );
+    }
+
+    #[test]
+    fn strict() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "1234567".separate_strict(policy),      Ok("1,234,567".to_owned()) );
+        assert_eq!( "-1234.5 kg".separate_strict(policy),   Ok("-1,234.5 kg".to_owned()) );
+        assert_eq!( "1234km".separate_strict(policy),       Ok("1,234km".to_owned()) );
+        assert_eq!( "1234e56".separate_strict(policy),      Ok("1,234e56".to_owned()) );
+        assert_eq!( "123O456".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'O', index: 3 }) );
+        assert_eq!( "No. 12l4".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'l', index: 6 }) );
+        assert_eq!( "€12Ω4".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'Ω', index: 5 }) );
+        assert_eq!( 123456.separate_strict(policy),         Ok("123,456".to_owned()) );
+    }
+
+    #[test]
+    fn strict_longer_interruptions() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "123OO456".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'O', index: 3 }) );
+        assert_eq!( "1234abc5 units".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'a', index: 4 }) );
+        assert_eq!( "1234e5x6".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'x', index: 6 }) );
+        assert_eq!( "1234eb5".separate_strict(policy),
+                    Err(UnexpectedChar { ch: 'e', index: 4 }) );
+        assert_eq!( "1234E+56".separate_strict(policy),     Ok("1,234E+56".to_owned()) );
+        assert_eq!( "1234kms".separate_strict(policy),      Ok("1,234kms".to_owned()) );
+    }
+
+    #[test]
+    fn regroup_spaces() {
+        let policy = SeparatorPolicy {
+            regroup: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        let once  = 1234567.separate_with_spaces();
+        let twice = once.separate_by_policy(policy);
+        assert_eq!( twice, "1 234 567" );
+        assert_eq!( twice.separate_by_policy(policy), twice );
+
+        assert_eq!( "12 34 567".separate_by_policy(policy),               "1 234 567" );
+        assert_eq!( "1\u{2009}234\u{202F}567".separate_by_policy(policy),  "1 234 567" );
+        assert_eq!( "1\u{A0}234 and 5".separate_by_policy(policy),        "1 234 and 5" );
+
+        let thin = SeparatorPolicy {
+            separator: "\u{2009}",
+            .. policy
+        };
+
+        assert_eq!( "1 234 567".separate_by_policy(thin),  "1\u{2009}234\u{2009}567" );
+
+        let comma = SeparatorPolicy {
+            regroup: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1 234".separate_by_policy(comma),     "1 234" );
+    }
+
+    #[test]
+    fn first_group() {
+        let policy = SeparatorPolicy {
+            separator:   " ",
+            groups:      &[4],
+            digits:      digits::ASCII_ALPHANUMERIC,
+            first_group: Some(1),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "A12345678".separate_by_policy(policy),     "A 1234 5678" );
+        assert_eq!( "#: B1234567".separate_by_policy(policy),   "#: B 1234 567" );
+    }
+
+    #[test]
+    fn scientific_passes_through() {
+        for &value in &[1234567.0, -0.000123, 6.02214076e23, 1.0, f64::MAX] {
+            let scientific = format!("{:e}", value);
+            assert_eq!( scientific.separate_with_commas(), scientific );
+            assert_eq!( format!("{:E}", value).separate_with_commas(),
+                        format!("{:E}", value) );
+        }
+    }
+
+    #[test]
+    fn mantissa() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "12345.67e8".separate_mantissa(policy),   "12,345.67e8" );
+        assert_eq!( "-1234E-5 m".separate_mantissa(policy),   "-1,234E-5 m" );
+        assert_eq!( "1.234567e6".separate_mantissa(policy),   "1.234567e6" );
+        assert_eq!( "1234567".separate_mantissa(policy),      "1234567" );
+        assert_eq!( "1234567e".separate_mantissa(policy),     "1234567e" );
+        assert_eq!( format!("{:e}", 1234567.0).separate_mantissa(policy),
+                    "1.234567e6" );
+
+        let superscript = SeparatorPolicy {
+            superscript_exponent: true,
+            .. policy
+        };
+
+        assert_eq!( "12345e6".separate_mantissa(superscript), "12,345 × 10⁶" );
+    }
+
+    #[test]
+    fn collapse_separators() {
+        let doubled = SeparatorPolicy {
+            groups: &[3, 0, 3],
+            .. policies::COMMA_SEPARATOR
+        };
+        let collapsed = SeparatorPolicy {
+            collapse_separators: true,
+            .. doubled
+        };
+
+        assert_eq!( 1234567.separate_by_policy(doubled),     "1,234,,567" );
+        assert_eq!( 1234567.separate_by_policy(collapsed),   "1,234,567" );
+        assert_eq!( (-1234.5).separate_by_policy(collapsed), "-1,234.5" );
+
+        let marked = SeparatorPolicy {
+            separators_after: &[(1, "'")],
+            .. doubled
+        };
+
+        assert_eq!( 1234567.separate_by_policy(marked),      "1'234',567" );
+        assert_eq!( 1234567.separate_by_policy(SeparatorPolicy {
+                        collapse_separators: true,
+                        .. marked
+                    }),                                      "1'234'567" );
+    }
+
+    #[test]
+    fn truncate_fraction() {
+        let policy = SeparatorPolicy {
+            truncate_fraction: Some(1),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.999".separate_by_policy(policy),         "1.9" );
+        assert_eq!( "-1234.999 m".separate_by_policy(policy),   "-1,234.9 m" );
+        assert_eq!( "1234.5".separate_by_policy(policy),        "1,234.5" );
+        assert_eq!( "1234.".separate_by_policy(policy),         "1,234." );
+        assert_eq!( "1234".separate_by_policy(policy),          "1,234" );
+        assert_eq!( 0.2999999999999999.separate_by_policy(policy), "0.2" );
+
+        let none = SeparatorPolicy {
+            truncate_fraction: Some(0),
+            .. policy
+        };
+
+        assert_eq!( "1234.999".separate_by_policy(none),        "1,234" );
+
+        let padded = SeparatorPolicy {
+            truncate_fraction: Some(2),
+            fraction_pad:      Some((2, '0')),
+            .. policy
+        };
+
+        assert_eq!( "1234.5678".separate_by_policy(padded),     "1,234.56" );
+        assert_eq!( "1234.5".separate_by_policy(padded),        "1,234.50" );
+
+        let mut in_place = String::from("1234.5678");
+        ::separate_in_place(&mut in_place, padded);
+        assert_eq!( in_place, "1,234.56" );
+    }
+
+    #[test]
+    fn map_digits() {
+        fn fullwidth(c: char) -> char {
+            c.to_digit(10)
+                .and_then(|d| ::std::char::from_u32('０' as u32 + d))
+                .unwrap_or(c)
+        }
+
+        let policy = SeparatorPolicy {
+            map_digits: Some(fullwidth),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567.separate_by_policy(policy),        "１,２３４,５６７" );
+        assert_eq!( "-1234.5e6".separate_by_policy(policy),    "-１,２３４.５e6" );
+        assert_eq!( "no. 42 of 99".separate_by_policy(policy), "no. ４２ of 99" );
+
+        let mut in_place = String::from("1234");
+        ::separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "１,２３４" );
+    }
+
+    #[test]
+    fn formatter() {
+        let format = policies::SPACE_SEPARATOR.formatter();
+        let amounts = [1234.5, -98765.25, 0.5];
+
+        assert_eq!( amounts.iter().map(&format).collect::<Vec<_>>(),
+                    ["1 234.5", "-98 765.25", "0.5"] );
+        assert_eq!( format(&1e6), "1 000 000" );
+
+        let format_str = policies::COMMA_SEPARATOR.formatter::<str>();
+        let labels: Vec<String> = ["12345 items", "n/a"].iter()
+            .map(|&label| format_str(label))
+            .collect();
+
+        assert_eq!( labels, ["12,345 items", "n/a"] );
+    }
+
+    #[test]
+    fn group_exponent() {
+        let policy = SeparatorPolicy {
+            group_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e-1234567".separate_by_policy(policy),     "1.5e-1,234,567" );
+        assert_eq!( "-1.5E+1234567".separate_by_policy(policy),    "-1.5E+1,234,567" );
+        assert_eq!( "12345e67890 m".separate_by_policy(policy),    "12,345e67,890 m" );
+        assert_eq!( "1.5e-123".separate_by_policy(policy),         "1.5e-123" );
+        assert_eq!( "1.5e-".separate_by_policy(policy),            "1.5e-" );
+        assert_eq!( "1.5e-1234567".separate_with_commas(),         "1.5e-1234567" );
+
+        let padded = SeparatorPolicy {
+            fraction_pad: Some((2, '0')),
+            .. policy
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(padded),        "1.50e12,345" );
+
+        let superscript = SeparatorPolicy {
+            superscript_exponent: true,
+            .. policy
+        };
+
+        assert_eq!( "1.5e-1234".separate_by_policy(superscript),   "1.5 × 10⁻¹²³⁴" );
+    }
+
+    #[test]
+    fn group_exponent_grouping() {
+        let policy = SeparatorPolicy {
+            separator:      " ",
+            groups:         &[2],
+            group_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(policy),        "1.5e1 23 45" );
+
+        let joined = SeparatorPolicy {
+            bind_with_zwj: true,
+            .. policy
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(joined),
+                    "1.5e1 \u{200D}23 \u{200D}45" );
+
+        let marked = SeparatorPolicy {
+            separator_fn: Some(|boundary| if boundary == 1 { "-" } else { " " }),
+            .. policy
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(marked),        "1.5e1-23 45" );
+
+        let major = SeparatorPolicy {
+            major_separator: Some(("'", 2)),
+            .. policy
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(major),         "1.5e1'23 45" );
+
+        let from_left = SeparatorPolicy {
+            first_group: Some(1),
+            .. policy
+        };
+
+        assert_eq!( "1.5e12345".separate_by_policy(from_left),     "1.5e1 23 45" );
+        assert_eq!( "1.5e123456".separate_by_policy(from_left),    "1.5e1 23 45 6" );
+    }
+
+    #[test]
+    fn pivot() {
+        let policy = SeparatorPolicy {
+            groups: &[2],
+            pivot:  Some(4),
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "123456789".separate_by_policy(policy),        "12 34 56 78 9" );
+        assert_eq!( "SN 123456789".separate_by_policy(policy),     "SN 12 34 56 78 9" );
+        assert_eq!( "123".separate_by_policy(policy),              "1 23" );
+
+        let major = SeparatorPolicy {
+            separator_fn: Some(|boundary| if boundary == 2 { "-" } else { " " }),
+            .. policy
+        };
+
+        assert_eq!( "123456789".separate_by_policy(major),         "12 34-56 78 9" );
+    }
+
+    #[test]
+    fn trim_fraction_zeros() {
+        let policy = SeparatorPolicy {
+            trim_fraction_zeros: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1234.500".separate_by_policy(policy),         "1,234.5" );
+        assert_eq!( "1234.000".separate_by_policy(policy),         "1,234" );
+        assert_eq!( "1234.".separate_by_policy(policy),            "1,234" );
+        assert_eq!( "1234.050 m".separate_by_policy(policy),       "1,234.05 m" );
+        assert_eq!( "1200".separate_by_policy(policy),             "1,200" );
+        assert_eq!( "1.500e10".separate_by_policy(policy),         "1.5e10" );
+        assert_eq!( "1234.500".separate_with_commas(),             "1,234.500" );
+
+        let padded = SeparatorPolicy {
+            fraction_pad: Some((2, '0')),
+            .. policy
+        };
+
+        assert_eq!( "1234.500".separate_by_policy(padded),         "1,234.50" );
+        assert_eq!( "1234.000".separate_by_policy(padded),         "1,234.00" );
+
+        let truncated = SeparatorPolicy {
+            truncate_fraction: Some(2),
+            .. policy
+        };
+
+        assert_eq!( "1234.5049".separate_by_policy(truncated),     "1,234.5" );
+    }
+
+    #[test]
+    fn ascii_fallback() {
+        let policy = SeparatorPolicy {
+            separator:      "\u{202F}",
+            ascii_fallback: Some(" "),
+            .. policies::COMMA_SEPARATOR
+        };
+        let ascii = SeparatorPolicy {
+            ascii_only: true,
+            .. policy
+        };
+
+        assert_eq!( 1234567.separate_by_policy(policy),            "1\u{202F}234\u{202F}567" );
+        assert_eq!( 1234567.separate_by_policy(ascii),             "1 234 567" );
+
+        let no_fallback = SeparatorPolicy {
+            ascii_fallback: None,
+            .. ascii
+        };
+
+        assert_eq!( 1234567.separate_by_policy(no_fallback),       "1\u{202F}234\u{202F}567" );
+
+        let mixed = SeparatorPolicy {
+            major_separator: Some(("\u{2009}", 2)),
+            bind_with_zwj:   true,
+            ascii_fallback:  Some("'"),
+            .. policies::DOT_SEPARATOR
+        };
+
+        assert_eq!( 123456789.separate_by_policy(mixed),
+                    "123\u{2009}\u{200D}456.\u{200D}789" );
+        assert_eq!( 123456789.separate_by_policy(SeparatorPolicy { ascii_only: true, .. mixed }),
+                    "123'456.789" );
+
+        let regroup = SeparatorPolicy {
+            regroup: true,
+            .. ascii
+        };
+
+        assert_eq!( "12 34 567".separate_by_policy(regroup),       "1 234 567" );
+    }
+
+    #[test]
+    fn reversed() {
+        let policies = [
+            policies::COMMA_SEPARATOR,
+            policies::HEX_FOUR,
+            SeparatorPolicy {
+                separator:     "\u{202F}",
+                bind_with_zwj: true,
+                groups:        &[3, 2],
+                fraction_pad:  Some((3, '0')),
+                map_digits:    Some(|c| c.to_ascii_uppercase()),
+                .. policies::COMMA_SEPARATOR
+            },
+        ];
+
+        for &policy in &policies {
+            for s in &["", "abc", "1234567", "-1234567.5 m", "≈ 98765e12", "0xdeadbeef"] {
+                let reversed = s.separate_reversed(policy);
+                assert_eq!( reversed.chars().rev().collect::<String>(),
+                            s.separate_by_policy(policy) );
+            }
+        }
+
+        assert_eq!( 1234567.separate_reversed(policies::COMMA_SEPARATOR), "765,432,1" );
+        assert_eq!( (-1234.5).separate_reversed(policies::COMMA_SEPARATOR), "5.432,1-" );
+    }
+
+    #[test]
+    fn reversed_keeps_separators_whole() {
+        let policy = SeparatorPolicy {
+            separator: "<>",
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567.separate_reversed(policy),             "765<>432<>1" );
+        assert_eq!( "7654321".separate_from_reversed(policy),      "765<>432<>1" );
+        assert_eq!( "1234567890123".separate_reversed(SeparatorPolicy {
+                        elide_middle: Some((1, "...>")),
+                        .. policy
+                    }),
+                    "321...>1" );
+        assert_eq!( 1234.separate_reversed(SeparatorPolicy {
+                        separator:     " ,",
+                        bind_with_zwj: true,
+                        .. policy
+                    }),
+                    "432\u{200D} ,1" );
+    }
+
+    #[test]
+    fn from_reversed() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "7654321".separate_from_reversed(policy),      "765,432,1" );
+        assert_eq!( "5.4321-".separate_from_reversed(policy),      "5.432,1-" );
+        assert_eq!( "m 5.7654321".separate_from_reversed(policy),  "m 5.765,432,1" );
+        assert_eq!( "321".separate_from_reversed(policy),          "321" );
+        assert_eq!( "".separate_from_reversed(policy),             "" );
+
+        for s in &["1234567", "-98765.4321", "≈ 1234 items"] {
+            let reversed: String = s.chars().rev().collect();
+            assert_eq!( reversed.separate_from_reversed(policy), s.separate_reversed(policy) );
+        }
+    }
+
+    #[test]
+    fn elide_middle() {
+        let policy = SeparatorPolicy {
+            elide_middle: Some((2, "…")),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "123456789012345678901234567890123456".separate_by_policy(policy),
+                    "123,456…123,456" );
+        assert_eq!( "1234567890123".separate_by_policy(policy),    "1,234…890,123" );
+        assert_eq!( "x=1234567890123.5 m".separate_by_policy(policy), "x=1,234…890,123.5 m" );
+        assert_eq!( "1234567890".separate_by_policy(policy),       "1,234,567,890" );
+        assert_eq!( "123".separate_by_policy(policy),              "123" );
+
+        let none = SeparatorPolicy { elide_middle: Some((0, "…")), .. policy };
+        assert_eq!( "1234567890123".separate_by_policy(none),      "1,234,567,890,123" );
+
+        let huge = SeparatorPolicy { elide_middle: Some((usize::MAX, "…")), .. policy };
+        assert_eq!( "1234567890123".separate_by_policy(huge),      "1,234,567,890,123" );
+
+        let one = SeparatorPolicy { elide_middle: Some((1, "...")), .. policies::SPACE_SEPARATOR };
+        assert_eq!( "1234567".separate_by_policy(one),             "1...567" );
+
+        let elided = "1234567890123";
+        let mut in_place = elided.to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "1,234…890,123" );
+        assert_eq!( (&elided).separated(policy).to_string(),       "1,234…890,123" );
+        assert_eq!( elided.separate_reversed(policy),              "321,098…432,1" );
+    }
+
+    #[test]
+    fn hashed() {
+        let policy = policies::COMMA_SEPARATOR;
+        let (text, hash) = 1234567.separate_hashed(policy);
+
+        assert_eq!( text, "1,234,567" );
+        assert_eq!( 1234567u64.separate_hashed(policy),             (text.clone(), hash) );
+        assert_eq!( "1234567".separate_hashed(policy),              (text.clone(), hash) );
+        assert_eq!( "1,234,567".separate_hashed(SeparatorPolicy { regroup: true, .. policy }),
+                    (text, hash) );
+        assert_ne!( 1234568.separate_hashed(policy).1,              hash );
+        assert_ne!( 1234567.separate_hashed(policies::SPACE_SEPARATOR).1, hash );
+    }
+
+    #[test]
+    fn fractional_groups() {
+        let policy = SeparatorPolicy {
+            fractional_groups: Some(&[3]),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.56789.separate_by_policy(policy),         "1,234.567,89" );
+        assert_eq!( "0.123456".separate_by_policy(policy),         "0.123,456" );
+        assert_eq!( "0.1234567".separate_by_policy(policy),        "0.123,456,7" );
+        assert_eq!( "-12.5e-7".separate_by_policy(policy),         "-12.5e-7" );
+        assert_eq!( (&"3.14159265").separated(policy).to_string(), "3.141,592,65" );
+
+        let mut in_place = "x = 1234.5678 m".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "x = 1,234.567,8 m" );
+    }
+
+    #[test]
+    fn fractional_groups_with_zwj() {
+        let policy = SeparatorPolicy {
+            fractional_groups: Some(&[3]),
+            bind_with_zwj:     true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1234567.123456".separate_by_policy(policy),
+                    "1,\u{200D}234,\u{200D}567.123,\u{200D}456" );
+        assert_eq!( (&"0.1234").separated(policy).to_string(),     "0.123,\u{200D}4" );
+
+        let mut in_place = "1234.5678".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "1,\u{200D}234.567,\u{200D}8" );
+    }
+
+    #[test]
+    fn fractional_groups_without_fraction() {
+        let policy = SeparatorPolicy {
+            fractional_groups: Some(&[3]),
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( 1234567.separate_by_policy(policy),            "1 234 567" );
+        assert_eq!( "1234.".separate_by_policy(policy),            "1 234." );
+        assert_eq!( "12".separate_by_policy(policy),               "12" );
+    }
+
+    #[test]
+    fn fractional_groups_uneven() {
+        let policy = SeparatorPolicy {
+            fractional_groups: Some(&[2, 3]),
+            fraction_pad:      Some((8, ' ')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.23456789".separate_by_policy(policy),       "1.23,456,789" );
+        assert_eq!( "1.2345".separate_by_policy(policy),           "1.23,45    " );
+        assert_eq!( "1.23456789".separate_by_policy(SeparatorPolicy {
+                        fractional_groups: Some(&[4, 0]),
+                        .. policy
+                    }),
+                    "1.2345,6789" );
+        assert_eq!( "1.2345678901".separate_by_policy(SeparatorPolicy {
+                        fractional_groups: Some(&[4, 0]),
+                        fraction_pad:      None,
+                        .. policy
+                    }),
+                    "1.2345,678901" );
+    }
+
+    #[test]
+    fn min_digits() {
+        let policy = SeparatorPolicy {
+            min_digits: 5,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "2024".separate_by_policy(policy),             "2024" );
+        assert_eq!( "12345".separate_by_policy(policy),            "12,345" );
+        assert_eq!( (-1234.5678).separate_by_policy(policy),       "-1234.5678" );
+        assert_eq!( "-12345.5".separate_by_policy(policy),         "-12,345.5" );
+        assert_eq!( "in 2024 AD".separate_by_policy(policy),       "in 2024 AD" );
+        assert_eq!( (&"1234").separated(policy).to_string(),       "1234" );
+        assert_eq!( (&"123456").separated(policy).to_string(),     "123,456" );
+
+        let mut in_place = "year 2024".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "year 2024" );
+
+        let mut in_place = "pop 12345".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "pop 12,345" );
+    }
+
+    #[test]
+    fn min_digits_uneven() {
+        let policy = SeparatorPolicy {
+            groups:     &[3, 2],
+            min_digits: 6,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 12345.separate_by_policy(policy),              "12345" );
+        assert_eq!( 123456.separate_by_policy(policy),             "1,23,456" );
+        assert_eq!( "-12345.678".separate_by_policy(policy),       "-12345.678" );
+        assert_eq!( "1234567".separate_by_policy(policy),          "12,34,567" );
+    }
+
+    #[test]
+    fn padding_with_group_exponent() {
+        let policy = SeparatorPolicy {
+            min_integer_digits: 6,
+            group_exponent:     true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e12".separate_by_policy(policy),           "000001.5e12" );
+        assert_eq!( "1.5e12".separate_by_policy(SeparatorPolicy {
+                        group_padding: true,
+                        .. policy
+                    }),
+                    "000,001.5e12" );
+
+        let threshold = SeparatorPolicy {
+            min_digits:     8,
+            group_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e1234567".separate_by_policy(threshold),   "1.5e1,234,567" );
+    }
+
+    #[test]
+    fn padding_grouped() {
+        let policy = SeparatorPolicy {
+            min_integer_digits: 6,
+            group_padding:      true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 42.separate_by_policy(policy),                 "000,042" );
+        assert_eq!( (-1234.5).separate_by_policy(policy),          "-001,234.5" );
+        assert_eq!( 1234567.separate_by_policy(policy),            "1,234,567" );
+        assert_eq!( "none".separate_by_policy(policy),             "none" );
+        assert_eq!( 0.separate_by_policy(SeparatorPolicy {
+                        min_integer_digits: 100,
+                        .. policy
+                    }),
+                    format!("0{}", ",000".repeat(33)) );
+        assert_eq!( 42.separate_by_policy(SeparatorPolicy {
+                        groups: &[3, 2],
+                        .. policy
+                    }),
+                    "0,00,042" );
+        assert_eq!( 42.separated(policy).to_string(),              "000,042" );
+    }
+
+    #[test]
+    fn padding_ungrouped() {
+        let policy = SeparatorPolicy {
+            min_integer_digits: 6,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 42.separate_by_policy(policy),                 "000042" );
+        assert_eq!( (-1234.5).separate_by_policy(policy),          "-001,234.5" );
+        assert_eq!( 1234.separate_by_policy(SeparatorPolicy {
+                        min_integer_digits: 8,
+                        .. policy
+                    }),
+                    "00001,234" );
+        assert_eq!( 1234567.separate_by_policy(policy),            "1,234,567" );
+
+        let mut in_place = "x=42".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "x=000042" );
+    }
+
+    #[test]
+    fn non_positional_digits() {
+        // The Ethiopic numerals, from ፩ (one) to ፼ (ten thousand).
+        let ethiopic: Vec<char> = ('\u{1369}' ..= '\u{137C}').collect();
+        let policy = SeparatorPolicy {
+            digits: &ethiopic,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        // 1,234, written as twelve hundreds and thirty-four.
+        assert_eq!( "፲፪፻፴፬".separate_by_policy(policy),          "፲፪,፻፴፬" );
+        assert_eq!( "-፼፼፼፼".separate_by_policy(policy),         "-፼,፼፼፼" );
+        assert_eq!( "፩".separate_by_policy(policy),              "፩" );
+        assert_eq!( "፲፪፻፴፬".separate_reversed(policy),          "፬፴፻,፪፲" );
+
+        let mut in_place = "፲፪፻፴፬ ብር".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "፲፪,፻፴፬ ብር" );
+
+        let long: String = ethiopic.iter().cycle().take(100).collect();
+        assert_eq!( long.separate_by_policy(policy).replace(',', ""), long );
+    }
+
+    #[test]
+    fn tracked() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (&"12").separate_tracked(policy),              ("12".to_owned(), false) );
+        assert_eq!( (&"1234").separate_tracked(policy),            ("1,234".to_owned(), true) );
+        assert_eq!( (-1234.5).separate_tracked(policy),            ("-1,234.5".to_owned(), true) );
+        assert_eq!( (&"no digits").separate_tracked(policy),       ("no digits".to_owned(), false) );
+        assert_eq!( (&"1,234").separate_tracked(SeparatorPolicy { regroup: true, .. policy }),
+                    ("1,234".to_owned(), false) );
+        assert_eq!( (&"12").separate_tracked(SeparatorPolicy {
+                        fraction_pad: Some((1, ' ')),
+                        .. policy
+                    }),
+                    ("12  ".to_owned(), true) );
+    }
+
+    #[test]
+    fn debug_brackets() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1234567.separate_debug(policy),                "[1][234][567]" );
+        assert_eq!( 123.separate_debug(policy),                    "[123]" );
+        assert_eq!( "x=-12345.678 m".separate_debug(policy),       "x=-[12][345].678 m" );
+        assert_eq!( "abc".separate_debug(policy),                  "abc" );
+        assert_eq!( "1,234,567".separate_debug(SeparatorPolicy { regroup: true, .. policy }),
+                    "[1][234][567]" );
+        assert_eq!( "1234567".separate_debug(SeparatorPolicy { groups: &[3, 0, 3], .. policy }),
+                    "[1][234][][567]" );
+        assert_eq!( "1234567890123".separate_debug(SeparatorPolicy {
+                        elide_middle: Some((1, "…")),
+                        .. policy
+                    }),
+                    "[1]…[123]" );
+    }
+
+    #[test]
+    fn digit_fn() {
+        let policy = SeparatorPolicy {
+            digit_fn: Some(&|c| "02468".contains(c)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "2468024".separate_by_policy(policy),          "2,468,024" );
+        assert_eq!( "1246802".separate_by_policy(policy),          "1246,802" );
+        assert_eq!( "2468.2413".separate_by_policy(policy),        "2,468.2413" );
+        assert_eq!( "13579".separate_by_policy(policy),            "13579" );
+
+        let mut in_place = "x 2468024 y".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "x 2,468,024 y" );
+
+        let letters = SeparatorPolicy {
+            digit_fn: Some(&char::is_alphabetic),
+            groups:   &[4],
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "#1 ABCDEFGHÉ".separate_by_policy(letters),    "#1 A BCDE FGHÉ" );
+    }
+
+    #[test]
+    fn digit_fn_captures() {
+        // As if read from a configuration file.
+        let configured = String::from("xyz");
+        let is_digit   = |c| configured.contains(c);
+        let policy = SeparatorPolicy {
+            digit_fn: Some(&is_digit),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "id xyzzyx!".separate_by_policy(policy),       "id xyz,zyx!" );
+        assert_eq!( 1234.separate_by_policy(policy),               "1234" );
+        assert!( format!("{:?}", policy).contains("digit_fn: Some(\"<closure>\")") );
+    }
+
+    #[test]
+    fn policy_auto_traits() {
+        use std::panic::{RefUnwindSafe, UnwindSafe};
+
+        fn check<T: Send + Sync + UnwindSafe + RefUnwindSafe>() {}
+        check::<SeparatorPolicy>();
+    }
+
+    #[test]
+    fn auto_isolate_in_rtl_text() {
+        let policy = SeparatorPolicy {
+            auto_isolate: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "מחיר: 1234567 ₪".separate_by_policy(policy),
+                    "מחיר: \u{2066}1 234 567\u{2069} ₪" );
+        assert_eq!( "السعر -1234.5e3 دينار".separate_by_policy(policy),
+                    "السعر \u{2066}-1 234.5e3\u{2069} دينار" );
+        assert_eq!( "1234 שקל".separate_by_policy(policy),
+                    "\u{2066}1 234\u{2069} שקל" );
+        assert_eq!( "מחיר: 12".separate_by_policy(policy),
+                    "מחיר: \u{2066}12\u{2069}" );
+
+        let mut in_place = "מחיר: 1234567".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "מחיר: \u{2066}1 234 567\u{2069}" );
+        assert_eq!( (&"מחיר: 1234567").separated(policy).to_string(),
+                    "מחיר: \u{2066}1 234 567\u{2069}" );
+    }
+
+    #[test]
+    fn auto_isolate_in_ltr_text() {
+        let policy = SeparatorPolicy {
+            auto_isolate: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "x = 1234567".separate_by_policy(policy),      "x = 1 234 567" );
+        assert_eq!( "-1234.5 €".separate_by_policy(policy),        "-1 234.5 €" );
+        assert_eq!( "מחיר".separate_by_policy(policy),             "מחיר" );
+    }
+
+    #[test]
+    fn prefer_power_of_ten() {
+        let policy = SeparatorPolicy {
+            prefer_power_of_ten: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1000000.separate_by_policy(policy),            "10^6" );
+        assert_eq!( 1.separate_by_policy(policy),                  "10^0" );
+        assert_eq!( 10.separate_by_policy(policy),                 "10^1" );
+        assert_eq!( (-1000).separate_by_policy(policy),            "-10^3" );
+        assert_eq!( "x = 1000 m".separate_by_policy(policy),       "x = 10^3 m" );
+        assert_eq!( 1000000u64.separated(policy).to_string(),      "10^6" );
+
+        let mut in_place = "1000000".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "10^6" );
+    }
+
+    #[test]
+    fn prefer_power_of_ten_only_for_exact_powers() {
+        let policy = SeparatorPolicy {
+            prefer_power_of_ten: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 12345.separate_by_policy(policy),              "12,345" );
+        assert_eq!( 1000001.separate_by_policy(policy),            "1,000,001" );
+        assert_eq!( 0.separate_by_policy(policy),                  "0" );
+        assert_eq!( "1000.5".separate_by_policy(policy),           "1,000.5" );
+        assert_eq!( "1000e6".separate_by_policy(policy),           "1,000e6" );
+        assert_eq!( "2000".separate_by_policy(policy),             "2,000" );
+    }
+
+    #[test]
+    fn prefer_power_of_ten_with_group_exponent() {
+        let policy = SeparatorPolicy {
+            prefer_power_of_ten: true,
+            group_exponent:      true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e1000".separate_by_policy(policy),         "1.5e1,000" );
+        assert_eq!( "1e-1000000".separate_by_policy(policy),       "1e-1,000,000" );
+    }
+
+    #[test]
+    fn iso_currency_after() {
+        let policy = SeparatorPolicy {
+            iso_currency: Some(("EUR", CodePlacement::After)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.56.separate_by_policy(policy),            "1,234.56 EUR" );
+        assert_eq!( (-1234.56).separate_by_policy(policy),         "-1,234.56 EUR" );
+        assert_eq!( "total: 1234567 due".separate_by_policy(policy),
+                    "total: 1,234,567 EUR due" );
+        assert_eq!( "n/a".separate_by_policy(policy),              "n/a" );
+    }
+
+    #[test]
+    fn iso_currency_before() {
+        let policy = SeparatorPolicy {
+            iso_currency: Some(("USD", CodePlacement::Before)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.56.separate_by_policy(policy),            "USD 1,234.56" );
+        assert_eq!( (-1234.56).separate_by_policy(policy),         "USD -1,234.56" );
+        assert_eq!( 12.separate_by_policy(policy),                 "USD 12" );
+        assert_eq!( (-1234.5).separated(policy).to_string(),       "USD -1,234.5" );
+
+        let mut in_place = "-1234567".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "USD -1,234,567" );
+    }
+
+    #[test]
+    fn iso_currency_with_group_exponent() {
+        let after = SeparatorPolicy {
+            iso_currency:   Some(("EUR", CodePlacement::After)),
+            group_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1.5e1234567".separate_by_policy(after),       "1.5e1,234,567 EUR" );
+
+        let before = SeparatorPolicy {
+            iso_currency: Some(("EUR", CodePlacement::Before)),
+            .. after
+        };
+
+        assert_eq!( "-1.5e-1234567".separate_by_policy(before),    "EUR -1.5e-1,234,567" );
+    }
+
+    #[test]
+    fn min_separator_group() {
+        let policy = SeparatorPolicy {
+            min_separator_group: 1,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567890.separate_by_policy(policy),         "1,234,567890" );
+        assert_eq!( 1234567.separate_by_policy(policy),            "1,234567" );
+        assert_eq!( 123456.separate_by_policy(policy),             "123456" );
+        assert_eq!( (-1234567.5).separate_by_policy(policy),       "-1,234567.5" );
+        assert_eq!( 1234567890.separate_by_policy(SeparatorPolicy {
+                        min_separator_group: 3,
+                        .. policy
+                    }),
+                    "1234567890" );
+
+        let mut in_place = "1234567890".to_owned();
+        separate_in_place(&mut in_place, policy);
+        assert_eq!( in_place, "1,234,567890" );
+    }
+
+    #[test]
+    fn too_short_to_separate() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert!( is_too_short("12", &policy) );
+        assert!( is_too_short("-123.4567 m", &policy) );
+        assert!( is_too_short("no digits", &policy) );
+        assert!( !is_too_short("1234", &policy) );
+        assert!( !is_too_short("x1234567", &policy) );
+        assert!( !is_too_short("12", &SeparatorPolicy { fraction_pad: Some((2, '0')), .. policy }) );
+        assert!( !is_too_short("12", &SeparatorPolicy { map_digits: Some(|c| c), .. policy }) );
+        assert!( !is_too_short("1_2", &SeparatorPolicy { ignorable: &['_'], .. policy }) );
+        assert!( is_too_short("12345", &SeparatorPolicy { groups: &[0, 5], .. policy }) );
+        assert!( is_too_short("12345", &SeparatorPolicy { groups: &[], .. policy }) );
+
+        for s in &["", "0", "12", "123", "-99.5", "x=7 m", "1.2.3", "123e45", "1234"] {
+            assert_eq!( s.separate_by_policy(policy), separate_chars(s, policy, 0) );
+        }
+
+        assert_eq!( "1_2".separate_by_policy(SeparatorPolicy { ignorable: &['_'], .. policy }),
+                    "12" );
+    }
+
+    #[test]
+    fn integer_only() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "1234.567".separate_integer_only(policy),   "1,234" );
+        assert_eq!( (-1234.5).separate_integer_only(policy),    "-1,234" );
+        assert_eq!( "≈ 12345 items".separate_integer_only(policy), "≈ 12,345" );
+        assert_eq!( 0.999.separate_integer_only(policy),        "0" );
+        assert_eq!( "none".separate_integer_only(policy),       "none" );
+    }
+
+    #[test]
+    fn trait_objects() {
+        let values: Vec<Box<dyn Separable>> = vec![
+            Box::new(135u8),
+            Box::new(-1234567i32),
+            Box::new(12345.678f32),
+            Box::new(u128::MAX),
+            Box::new("0xdeadbeef".to_owned()),
+        ];
+
+        let formatted: Vec<String> = values.iter()
+            .map(|value| value.separate_with_commas())
+            .collect();
+
+        assert_eq!( formatted, [
+            "135",
+            "-1,234,567",
+            "12,345.678",
+            "340,282,366,920,938,463,463,374,607,431,768,211,455",
+            "0xdeadbeef",
+        ] );
+
+        let value: &dyn Separable = &1234;
+        assert_eq!( value.try_separate(policies::SPACE_SEPARATOR), Ok("1 234".to_owned()) );
+    }
+
+    #[test]
+    fn separators_after() {
+        let policy = SeparatorPolicy {
+            separators_after: &[(2, " ")],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234567890123u64.separate_by_policy(policy), "1 234 567,890,123" );
+        assert_eq!( 1234567.separate_by_policy(policy),          "1,234,567" );
+        assert_eq!( 1234567890.separate_by_policy(policy),       "1 234,567,890" );
+    }
+
+    #[test]
+    fn several_separators_after() {
+        let policy = SeparatorPolicy {
+            separators_after: &[(0, "."), (1, "'"), (3, "__")],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 123456789012345u64.separate_by_policy(policy),
+                    "123__456'789'012.345" );
+    }
+
+    #[test]
+    fn auto_radix() {
+        let policy = policies::UNDERSCORE_SEPARATOR;
+
+        assert_eq!( "0xdeadbeef".separate_auto(policy),  "0xdead_beef" );
+        assert_eq!( "0XDeadBeef".separate_auto(policy),  "0XDead_Beef" );
+        assert_eq!( "0b10101100".separate_auto(policy),  "0b1010_1100" );
+        assert_eq!( "0o1234567".separate_auto(policy),   "0o1_234_567" );
+        assert_eq!( "12345".separate_auto(policy),       "12_345" );
+        assert_eq!( "-0x1ffff".separate_auto(policy),    "-0x1_ffff" );
+        assert_eq!( "\u{2212}0x1ffff".separate_auto(policy),
+                    "\u{2212}0x1_ffff" );
+        assert_eq!( "+0b11111".separate_auto(policy),    "+0b1_1111" );
+        assert_eq!( "0".separate_auto(policy),           "0" );
+        assert_eq!( "0x".separate_auto(policy),          "0x" );
+        assert_eq!( "-12345.678".separate_auto(policy),  "-12_345.678" );
+    }
+
+    #[test]
+    fn auto_radix_alternate_forms() {
+        let policy = policies::SPACE_SEPARATOR;
+
+        assert_eq!( format!("{:#x}", 0x1234_abcd).separate_auto(policy),     "0x1234 abcd" );
+        assert_eq!( format!("{:#X}", 0x1234_abcd).separate_auto(policy),     "0x1234 ABCD" );
+        assert_eq!( format!("{:#010x}", 0xbeef).separate_auto(policy),       "0x0000 beef" );
+        assert_eq!( format!("{:#x}", -1i16).separate_auto(policy),           "0xffff" );
+        assert_eq!( format!("{:#b}", 0b1_0110_0111).separate_auto(policy),  "0b1 0110 0111" );
+        assert_eq!( format!("{:#o}", 0o1234567).separate_auto(policy),       "0o1 234 567" );
+        assert_eq!( format!("{:#x}", 0).separate_auto(policy),               "0x0" );
+    }
+
+    #[test]
+    fn fraction_pad() {
+        let policy = SeparatorPolicy {
+            fraction_pad: Some((3, ' ')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.5.separate_by_policy(policy),     "1,234.5  " );
+        assert_eq!( 1234.56.separate_by_policy(policy),    "1,234.56 " );
+        assert_eq!( 1234.567.separate_by_policy(policy),   "1,234.567" );
+        assert_eq!( 1234.5678.separate_by_policy(policy),  "1,234.5678" );
+        assert_eq!( 1234.separate_by_policy(policy),       "1,234    " );
+        assert_eq!( "-1.5 kg".separate_by_policy(policy),  "-1.5   kg" );
+        assert_eq!( "n/a".separate_by_policy(policy),      "n/a" );
+    }
+
+    #[test]
+    fn fraction_pad_char() {
+        let policy = SeparatorPolicy {
+            fraction_pad: Some((2, '_')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "12345.".separate_by_policy(policy),   "12,345.__" );
+    }
+
+    fn malformed_policy(on_malformed: OnMalformed) -> SeparatorPolicy<'static> {
+        SeparatorPolicy {
+            on_malformed,
+            .. policies::COMMA_SEPARATOR
+        }
+    }
+
+    #[test]
+    fn malformed_group_first() {
+        let policy = malformed_policy(OnMalformed::GroupFirst);
+
+        assert_eq!( "1.2.3".separate_by_policy(policy),     "1.2.3" );
+        assert_eq!( "1234.5.6".separate_by_policy(policy),  "1,234.5.6" );
+        assert_eq!( "1234.5.6".try_separate(policy),        Ok("1,234.5.6".to_owned()) );
+    }
+
+    #[test]
+    fn malformed_passthrough() {
+        let policy = malformed_policy(OnMalformed::Passthrough);
+
+        assert_eq!( "1.2.3".separate_by_policy(policy),     "1.2.3" );
+        assert_eq!( "1234.5.6".separate_by_policy(policy),  "1234.5.6" );
+        assert_eq!( "1234.5.6".try_separate(policy),        Ok("1234.5.6".to_owned()) );
+        assert_eq!( "1234.5.".separate_by_policy(policy),   "1,234.5." );
+    }
+
+    #[test]
+    fn malformed_error() {
+        let policy = malformed_policy(OnMalformed::Error);
+
+        assert_eq!( "1.2.3".try_separate(policy),           Err(SeparateError::Malformed) );
+        assert_eq!( "1234.5.6".try_separate(policy),        Err(SeparateError::Malformed) );
+        assert_eq!( "1234.5.6".separate_by_policy(policy),  "1234.5.6" );
+        assert_eq!( "1234.56".try_separate(policy),         Ok("1,234.56".to_owned()) );
+        assert_eq!( "v1234.5 of 2.0".try_separate(policy),  Ok("v1,234.5 of 2.0".to_owned()) );
+    }
+}
+
+#[cfg(test)]
+mod blanket_test {
+    use std::fmt;
+
+    use super::super::{Separable, policies};
+
+    struct Labeled(&'static str, i64, &'static str);
+
+    impl fmt::Display for Labeled {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{}{}{}", self.0, self.1, self.2)
+        }
+    }
+
+    #[test]
+    fn no_label() {
+        assert_eq!( Labeled("", 1234567, "").separate_with_commas(),
+                    "1,234,567" );
+        assert_eq!( Labeled("", -1234567, "").separate_with_commas(),
+                    "-1,234,567" );
+    }
+
+    #[test]
+    fn prefix() {
+        assert_eq!( Labeled("x=", 1234567, "").separate_with_commas(),
+                    "x=1,234,567" );
+        assert_eq!( Labeled("total: ", -1234, "").separate_with_commas(),
+                    "total: -1,234" );
+    }
+
+    #[test]
+    fn suffix() {
+        assert_eq!( Labeled("", 1234567, " units").separate_with_commas(),
+                    "1,234,567 units" );
+        assert_eq!( Labeled("", 1234, ".").separate_with_commas(),
+                    "1,234." );
+    }
+
+    #[test]
+    fn prefix_and_suffix() {
+        assert_eq!( Labeled("x=", 1234567, " units").separate_with_commas(),
+                    "x=1,234,567 units" );
+        assert_eq!( Labeled("[", 1234567, "]").separate_by_policy(policies::SPACE_SEPARATOR),
+                    "[1 234 567]" );
+    }
+
+    #[test]
+    fn later_digits_untouched() {
+        assert_eq!( Labeled("x=", 1234567, " of 7654321").separate_with_commas(),
+                    "x=1,234,567 of 7654321" );
+        assert_eq!( Labeled("no digits", 0, "").separate_with_commas(),
+                    "no digits0" );
+        assert_eq!( Labeled("v2 ", 1234567, "").separate_with_commas(),
+                    "v2 1234567" );
+    }
+}
+
+#[cfg(test)]
+mod no_panic_test {
+    use std::panic;
+
+    use super::super::{OnMalformed, Separable, SeparatorPolicy, policies, separate_in_place};
+    use super::super::helpers::SeparatorIterator;
+
+    const ALPHABET: &[char] = &[
+        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'f', 'x', 'e', 'E',
+        '.', ',', '-', '+', '_', ' ', '\u{202F}', '\u{2212}', '≈', '€', '😃',
+    ];
+
+    // A small xorshift generator, so the corpus is the same on every run.
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % n as u64) as usize
+        }
+
+        fn string(&mut self) -> String {
+            let len = self.below(48);
+            (0 .. len).map(|_| ALPHABET[self.below(ALPHABET.len())]).collect()
+        }
+    }
+
+    fn policies() -> Vec<SeparatorPolicy<'static>> {
+        let mut result = vec![
+            policies::COMMA_SEPARATOR,
+            policies::SPACE_SEPARATOR,
+            policies::DOT_SEPARATOR,
+            policies::UNDERSCORE_SEPARATOR,
+            policies::HEX_FOUR,
+        ];
+
+        for &groups in &[&[][..], &[0], &[0, 0], &[1], &[3, 0, 2], &[2, 0], &[255, 1]] {
+            result.push(SeparatorPolicy { groups, .. policies::COMMA_SEPARATOR });
+            result.push(SeparatorPolicy {
+                groups,
+                avoid_orphan:         true,
+                collapse_separators:  true,
+                fraction_pad:         Some((3, ' ')),
+                superscript_exponent: true,
+                on_malformed:         OnMalformed::Passthrough,
+                .. policies::COMMA_SEPARATOR
+            });
+            result.push(SeparatorPolicy {
+                groups,
+                regroup:             true,
+                first_group:         Some(0),
+                truncate_fraction:   Some(0),
+                trim_fraction_zeros: true,
+                group_exponent:      true,
+                .. policies::SPACE_SEPARATOR
+            });
+            result.push(SeparatorPolicy {
+                groups,
+                pivot:            Some(3),
+                major_separator:  Some(("'", 0)),
+                separators_after: &[(0, "."), (2, "'")],
+                bind_with_zwj:    true,
+                elide_middle:     Some((1, "…")),
+                .. policies::UNDERSCORE_SEPARATOR
+            });
+        }
+
+        result
+    }
+
+    #[test]
+    fn random_inputs() {
+        let policies = policies();
+        let mut rng  = Rng(0x2545_F491_4F6C_DD1D);
+
+        for _ in 0 .. 2000 {
+            let input = rng.string();
+
+            for &policy in &policies {
+                let result = panic::catch_unwind(|| {
+                    let mut in_place = input.clone();
+                    separate_in_place(&mut in_place, policy);
+                    (input.separate_by_policy(policy), in_place)
+                });
+
+                match result {
+                    Ok((separated, in_place)) => assert_eq!( separated, in_place ),
+                    Err(_) => panic!("panicked on {:?} with {:?}", input, policy),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_digit_counts() {
+        let policies = policies();
+        let mut rng  = Rng(0x9E37_79B9_7F4A_7C15);
+
+        for _ in 0 .. 2000 {
+            let len = rng.below(600);
+
+            for policy in &policies {
+                let mut iter   = SeparatorIterator::new(policy, len);
+                let separators = iter.sep_len();
+                let mut digits = 0;
+                let mut written = 0;
+
+                while let Some(boundary) = iter.next() {
+                    digits += 1;
+                    if boundary {
+                        written += iter.separators_here(false);
+                    }
+                }
+
+                assert_eq!( (digits, written), (len, separators),
+                            "{} digits with {:?}", len, policy );
+            }
+        }
+    }
+
+    #[test]
+    fn extreme_values() {
+        for policy in policies() {
+            for value in &[u128::MAX.to_string(), i128::MIN.to_string(), "9".repeat(10_000),
+                           f64::MAX.to_string(), f64::MIN_POSITIVE.to_string(),
+                           "1e-99999999999999999999".to_owned()] {
+                let _ = value.separate_by_policy(policy);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod fast_path_test {
+    use super::super::{CodePlacement, OnMalformed, SeparatorPolicy, policies,
+                       separate_in_place};
+    use super::super::ascii::separate_ascii;
+    use super::{is_too_short, separate_chars};
+
+    const INPUTS: &[&str] = &[
+        "", "0", "12", "123", "1234", "1000", "-1234567.891", "x=98765 units", "1_234_567",
+        "12 345 678", "12345e67", "1.5e10", "1234.5000", "0.000123", "1.2.3456", "abc",
+        "١٢٣٤٥", "שלום 12345", "1234567890123456789012345678901234567890",
+    ];
+
+    const ARABIC_INDIC: &[char] = &['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+
+    // One policy for each field of `SeparatorPolicy` with that field, and any
+    // it depends on, set to something other than its default.
+    fn policies() -> Vec<(&'static str, SeparatorPolicy<'static>)> {
+        let base = policies::COMMA_SEPARATOR;
+
+        // This names every field, so that adding one doesn’t compile until it
+        // has a case below.
+        let SeparatorPolicy {
+            separator: _, groups: _, digits: _, ignorable: _, avoid_orphan: _,
+            superscript_exponent: _, separators_after: _, fraction_pad: _, decimal_point: _,
+            on_malformed: _, fraction_digits: _, bind_with_zwj: _, regroup: _,
+            major_separator: _, separator_fn: _, first_group: _, collapse_separators: _,
+            truncate_fraction: _, map_digits: _, group_exponent: _, pivot: _,
+            trim_fraction_zeros: _, ascii_only: _, ascii_fallback: _, elide_middle: _,
+            digit_fn: _, auto_isolate: _, prefer_power_of_ten: _, iso_currency: _,
+            min_separator_group: _, min_integer_digits: _, group_padding: _,
+            fractional_groups: _, min_digits: _,
+        } = base;
+
+        vec![
+            ("separator",            SeparatorPolicy { separator: "'", .. base }),
+            ("separator",            SeparatorPolicy { separator: "\u{202F}", .. base }),
+            ("groups",               SeparatorPolicy { groups: &[3, 2], .. base }),
+            ("groups",               SeparatorPolicy { groups: &[2, 0, 3], .. base }),
+            ("digits",               SeparatorPolicy { digits: ARABIC_INDIC, .. base }),
+            ("ignorable",            SeparatorPolicy { ignorable: &['_', ' '], .. base }),
+            ("avoid_orphan",         SeparatorPolicy { avoid_orphan: true, .. base }),
+            ("superscript_exponent", SeparatorPolicy { superscript_exponent: true, .. base }),
+            ("separators_after",     SeparatorPolicy { separators_after: &[(1, "'")], .. base }),
+            ("fraction_pad",         SeparatorPolicy { fraction_pad: Some((4, '0')), .. base }),
+            ("decimal_point",        SeparatorPolicy { decimal_point: ',', .. base }),
+            ("on_malformed",         SeparatorPolicy {
+                                         on_malformed: OnMalformed::Passthrough,
+                                         .. base
+                                     }),
+            ("on_malformed",         SeparatorPolicy { on_malformed: OnMalformed::Error, .. base }),
+            ("fraction_digits",      SeparatorPolicy {
+                                         fraction_digits: Some(&['0', '1', '2']),
+                                         .. base
+                                     }),
+            ("bind_with_zwj",        SeparatorPolicy { bind_with_zwj: true, .. base }),
+            ("regroup",              SeparatorPolicy { regroup: true, .. policies::SPACE_SEPARATOR }),
+            ("major_separator",      SeparatorPolicy { major_separator: Some(("'", 2)), .. base }),
+            ("separator_fn",         SeparatorPolicy {
+                                         separator_fn: Some(|i| if i % 2 == 0 { "," } else { "." }),
+                                         .. base
+                                     }),
+            ("first_group",          SeparatorPolicy { first_group: Some(2), .. base }),
+            ("collapse_separators",  SeparatorPolicy {
+                                         groups:              &[3, 0, 2],
+                                         collapse_separators: true,
+                                         .. base
+                                     }),
+            ("truncate_fraction",    SeparatorPolicy { truncate_fraction: Some(1), .. base }),
+            ("map_digits",           SeparatorPolicy {
+                                         map_digits: Some(|c| if c == '0' { 'o' } else { c }),
+                                         .. base
+                                     }),
+            ("group_exponent",       SeparatorPolicy { group_exponent: true, .. base }),
+            ("pivot",                SeparatorPolicy { pivot: Some(3), .. base }),
+            ("trim_fraction_zeros",  SeparatorPolicy { trim_fraction_zeros: true, .. base }),
+            ("ascii_only",           SeparatorPolicy {
+                                         separator:  "\u{202F}",
+                                         ascii_only: true,
+                                         .. base
+                                     }),
+            ("ascii_fallback",       SeparatorPolicy {
+                                         separator:      "\u{202F}",
+                                         ascii_only:     true,
+                                         ascii_fallback: Some("'"),
+                                         .. base
+                                     }),
+            ("elide_middle",         SeparatorPolicy { elide_middle: Some((1, "…")), .. base }),
+            ("digit_fn",             SeparatorPolicy { digit_fn: Some(&char::is_numeric), .. base }),
+            ("auto_isolate",         SeparatorPolicy { auto_isolate: true, .. base }),
+            ("prefer_power_of_ten",  SeparatorPolicy { prefer_power_of_ten: true, .. base }),
+            ("iso_currency",         SeparatorPolicy {
+                                         iso_currency: Some(("USD", CodePlacement::Before)),
+                                         .. base
+                                     }),
+            ("min_separator_group",  SeparatorPolicy { min_separator_group: 1, .. base }),
+            ("min_integer_digits",   SeparatorPolicy { min_integer_digits: 6, .. base }),
+            ("group_padding",        SeparatorPolicy {
+                                         min_integer_digits: 6,
+                                         group_padding:      true,
+                                         .. base
+                                     }),
+            ("fractional_groups",    SeparatorPolicy { fractional_groups: Some(&[3]), .. base }),
+            ("min_digits",           SeparatorPolicy { min_digits: 5, .. base }),
+        ]
+    }
+
+    #[test]
+    fn agree_with_general_path() {
+        for (field, policy) in policies() {
+            for &s in INPUTS {
+                let expected = separate_chars(s, policy, 0);
+
+                if let Some(fast) = separate_ascii(s, &policy, 0) {
+                    assert_eq!( fast, expected, "ASCII path, {}, input {:?}", field, s );
+                }
+
+                let mut in_place = s.to_owned();
+                separate_in_place(&mut in_place, policy);
+                assert_eq!( in_place, expected, "in place, {}, input {:?}", field, s );
+
+                if is_too_short(s, &policy) {
+                    assert_eq!( s, expected, "too short, {}, input {:?}", field, s );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned when separators can’t be added as requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeparateError {
+    /// There are no digits to add separators to.
+    NoDigits,
+    /// The number is malformed, such as by having more than one decimal
+    /// point, and the policy says to report that.
+    Malformed,
+}
+
+impl fmt::Display for SeparateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            SeparateError::NoDigits  => "no digits to separate",
+            SeparateError::Malformed => "malformed number",
+        })
+    }
+}
+
+impl Error for SeparateError {}
+
+/// The error returned when a byte-level separator contains a non-ASCII byte,
+/// which could make the output invalid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NonAsciiSeparator {
+    /// The offending byte.
+    pub byte:  u8,
+    /// The offending byte’s index in the separator.
+    pub index: usize,
+}
+
+impl fmt::Display for NonAsciiSeparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "non-ASCII byte 0x{:02x} at index {} of separator", self.byte, self.index)
+    }
+}
+
+impl Error for NonAsciiSeparator {}
+
+/// The error returned when a character that isn’t one of the policy’s digits
+/// interrupts the first run of digits, such as the letter `O` in `123O456`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnexpectedChar {
+    /// The offending character.
+    pub ch:    char,
+    /// The offending character’s byte index in the formatted input.
+    pub index: usize,
+}
+
+impl fmt::Display for UnexpectedChar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected character {:?} at index {} of number", self.ch, self.index)
+    }
+}
+
+impl Error for UnexpectedChar {}
+
+/// The error returned when a grouping pattern, as passed to
+/// [`SeparatorPolicy::from_pattern`], can’t be understood.
+///
+/// [`SeparatorPolicy::from_pattern`]: struct.SeparatorPolicy.html#method.from_pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatternError {
+    /// The pattern has no separator between digit placeholders.
+    NoSeparator,
+    /// The pattern uses more than one separator.
+    MixedSeparators,
+    /// The pattern starts or ends with something other than a digit
+    /// placeholder, not counting a final `...`.
+    MissingDigits,
+    /// A group has more than 255 digit placeholders.
+    GroupTooLong,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            PatternError::NoSeparator     => "no separator in pattern",
+            PatternError::MixedSeparators => "pattern mixes separators",
+            PatternError::MissingDigits   => "pattern doesn’t start and end with digits",
+            PatternError::GroupTooLong    => "group in pattern is too long",
+        })
+    }
+}
+
+impl Error for PatternError {}
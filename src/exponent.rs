@@ -0,0 +1,137 @@
+/// Splits the text after a number’s integer digits into the fraction, that
+/// is, a decimal point followed by digits, and whatever follows it. The
+/// fraction is empty if the text doesn’t start with a decimal point.
+pub fn split_fraction<F>(after: &str, decimal_point: char, is_digit: F) -> (&str, &str)
+where F: Fn(char) -> bool {
+
+    let fraction_len = match after.chars().next() {
+        Some(c) if c == decimal_point =>
+            c.len_utf8() + len_matching(&after[c.len_utf8() ..], is_digit),
+        _ => 0,
+    };
+
+    after.split_at(fraction_len)
+}
+
+/// The part of a number’s text that follows its integer digits and
+/// fraction, split around an exponent such as the `e-7` in `1.5e-7`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exponent<'a> {
+    /// The exponent marker, `e` or `E`.
+    pub marker:   char,
+    /// The exponent’s sign, if any.
+    pub sign:     &'a str,
+    /// The exponent’s digits.
+    pub digits:   &'a str,
+    /// Whatever follows the exponent.
+    pub rest:     &'a str,
+}
+
+impl<'a> Exponent<'a> {
+    /// Splits the text after a number’s fraction around its exponent.
+    /// Returns `None` if the text doesn’t start with a complete exponent.
+    pub fn split(after: &'a str) -> Option<Self> {
+        let marker = match after.chars().next() {
+            Some(c @ 'e') | Some(c @ 'E') => c,
+            _                             => return None,
+        };
+        let after = &after[1 ..];
+
+        let sign_len = match after.chars().next() {
+            Some('+') | Some('-') => 1,
+            _                     => 0,
+        };
+        let (sign, after) = after.split_at(sign_len);
+
+        let digits_len = len_matching(after, |c| c.is_ascii_digit());
+        if digits_len == 0 {
+            return None;
+        }
+        let (digits, rest) = after.split_at(digits_len);
+
+        Some(Exponent { marker, sign, digits, rest })
+    }
+
+    /// Appends the exponent in the form `× 10ⁿ`, with superscript digits.
+    pub fn push_superscript(&self, result: &mut String) {
+        push_power_of_ten(result, self.sign == "-", self.digits);
+        result.push_str(self.rest);
+    }
+}
+
+/// Appends ` × 10ⁿ`, where `n` is given by the ASCII digits `digits` and is
+/// negative if `negative` is set.
+pub fn push_power_of_ten(result: &mut String, negative: bool, digits: &str) {
+    result.push_str(" × 10");
+    if negative {
+        result.push('⁻');
+    }
+    result.extend(digits.chars().map(superscript));
+}
+
+fn len_matching<F>(s: &str, pred: F) -> usize
+where F: Fn(char) -> bool {
+
+    s.char_indices()
+        .find(|p| !pred(p.1))
+        .map_or(s.len(), |p| p.0)
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = [
+    '⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹',
+];
+
+/// Maps an ASCII digit to its superscript form.
+fn superscript(digit: char) -> char {
+    digit.to_digit(10).map_or(digit, |d| SUPERSCRIPT_DIGITS[d as usize])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fractions() {
+        let is_digit = |c: char| c.is_ascii_digit();
+
+        assert_eq!( split_fraction(".5e3", '.', is_digit),    (".5", "e3") );
+        assert_eq!( split_fraction(". items", '.', is_digit), (".", " items") );
+        assert_eq!( split_fraction(" items", '.', is_digit),  ("", " items") );
+        assert_eq!( split_fraction("", '.', is_digit),        ("", "") );
+        assert_eq!( split_fraction(",25 €", ',', is_digit),   (",25", " €") );
+        assert_eq!( split_fraction(".25", ',', is_digit),     ("", ".25") );
+    }
+
+    #[test]
+    fn complete_exponents() {
+        assert_eq!( Exponent::split("e3"), Some(Exponent {
+            marker: 'e', sign: "", digits: "3", rest: "",
+        }) );
+        assert_eq!( Exponent::split("E-12 m"), Some(Exponent {
+            marker: 'E', sign: "-", digits: "12", rest: " m",
+        }) );
+    }
+
+    #[test]
+    fn not_exponents() {
+        assert_eq!( Exponent::split(""),        None );
+        assert_eq!( Exponent::split(".5e3"),    None );
+        assert_eq!( Exponent::split(" items"),  None );
+        assert_eq!( Exponent::split(" e3"),     None );
+    }
+
+    #[test]
+    fn dangling_exponents() {
+        assert_eq!( Exponent::split("e"),       None );
+        assert_eq!( Exponent::split("E+"),      None );
+        assert_eq!( Exponent::split("e-"),      None );
+        assert_eq!( Exponent::split("e+ m"),    None );
+        assert_eq!( Exponent::split("p3"),      None );
+    }
+
+    #[test]
+    fn superscripts() {
+        let digits: String = "0123456789".chars().map(superscript).collect();
+        assert_eq!( digits, "⁰¹²³⁴⁵⁶⁷⁸⁹" );
+    }
+}
@@ -0,0 +1,819 @@
+use super::{Separable, SeparatorPolicy};
+use super::display::separate_integer_len;
+use super::exponent::push_power_of_ten;
+
+/// Provides methods for formatting floating-point numbers that depend on
+/// their value, not just their digits, with separators between the digits.
+pub trait SeparableFloat: Separable {
+    /// Formats a ratio as a percentage with `decimals` digits after the
+    /// decimal point, grouping the integer part according to `policy` and
+    /// appending a percent sign.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparableFloat, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 0.1234567.separate_percent(2, policy),  "12.35%" );
+    /// assert_eq!( 12.3456.separate_percent(2, policy),    "1,234.56%" );
+    /// assert_eq!( (-0.5).separate_percent(0, policy),     "-50%" );
+    /// ```
+    fn separate_percent(&self, decimals: usize, policy: SeparatorPolicy) -> String;
+
+    /// Formats the number rounded to `sig` significant figures, keeping any
+    /// trailing zeros, and groups the integer part according to `policy`.
+    ///
+    /// Large numbers are rounded in their integer part and small numbers get
+    /// as many fractional digits as they need. Since trailing zeros are kept,
+    /// every value formatted with the same `sig` shows the same number of
+    /// digits, as for a table of measurements. A `sig` of zero is treated as
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparableFloat, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 1234.5.separate_sigfigs(6, policy),      "1,234.50" );
+    /// assert_eq!( 0.00012345.separate_sigfigs(3, policy),  "0.000123" );
+    /// assert_eq!( 1234567.0.separate_sigfigs(3, policy),   "1,230,000" );
+    /// ```
+    fn separate_sigfigs(&self, sig: usize, policy: SeparatorPolicy) -> String;
+
+    /// Formats the number in engineering notation, with a power of ten whose
+    /// exponent is a multiple of three and a mantissa of at least one and
+    /// less than a thousand, grouped according to `policy`.
+    ///
+    /// All the digits of the shortest representation of the number are
+    /// kept, and the exponent is written with superscript digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparableFloat, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 12345.678.separate_engineering(policy),  "12.345678 × 10³" );
+    /// assert_eq!( 0.00015.separate_engineering(policy),    "150 × 10⁻⁶" );
+    /// ```
+    fn separate_engineering(&self, policy: SeparatorPolicy) -> String;
+
+    /// Scales the number to the nearest SI prefix, such as `k` or `M`, and
+    /// formats it with three significant figures, a space, and the prefix.
+    ///
+    /// Numbers from one up to a thousand get no prefix or space. Numbers too
+    /// large for the largest prefix, `Q`, get a coefficient of a thousand or
+    /// more, which is grouped according to `policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparableFloat, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 1234567.0.separate_si(policy),   "1.23 M" );
+    /// assert_eq!( (-0.0025).separate_si(policy),   "-2.50 m" );
+    /// assert_eq!( 42.0.separate_si(policy),        "42.0" );
+    /// ```
+    fn separate_si(&self, policy: SeparatorPolicy) -> String;
+
+    /// Abbreviates the number with `K`, `M`, `B`, or `T` for thousands,
+    /// millions, billions, or trillions, formatting the coefficient with up
+    /// to `decimals` digits after the decimal point.
+    ///
+    /// Trailing zeros after the decimal point are dropped, so a thousand is
+    /// `1K` rather than `1.0K`. Numbers below a thousand get no suffix, and
+    /// numbers too large for `T` get a coefficient of a thousand or more,
+    /// which is grouped with commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::SeparableFloat;
+    ///
+    /// assert_eq!( 1234567.0.separate_abbreviated(1),       "1.2M" );
+    /// assert_eq!( 3.4e9.separate_abbreviated(1),           "3.4B" );
+    /// assert_eq!( (-5600.0).separate_abbreviated(1),       "-5.6K" );
+    /// assert_eq!( 1000.0.separate_abbreviated(1),          "1K" );
+    /// ```
+    fn separate_abbreviated(&self, decimals: usize) -> String;
+
+    /// Returns an iterator over successively more compact renderings of the
+    /// number, for layouts that try each until one fits: the number grouped
+    /// according to `policy`, then with an SI prefix as by
+    /// [`separate_si`], then in scientific notation with three significant
+    /// figures. The iterator stops after the first rendering that is at most
+    /// `max_width` characters wide, or after the last if none is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparableFloat, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 1234.0.separate_candidates(policy, 8).collect::<Vec<_>>(), ["1,234"] );
+    /// assert_eq!( 1.234e20.separate_candidates(policy, 8).last().unwrap(), "123 E" );
+    /// ```
+    ///
+    /// [`separate_si`]: #tymethod.separate_si
+    fn separate_candidates<'a>(&self, policy: SeparatorPolicy<'a>, max_width: usize)
+                               -> Candidates<'a>;
+}
+
+/// An iterator over more and more compact renderings of a number, as
+/// returned by
+/// [`SeparableFloat::separate_candidates`](trait.SeparableFloat.html#tymethod.separate_candidates).
+#[derive(Debug, Clone)]
+pub struct Candidates<'a> {
+    value:     f64,
+    // The number as written by `Display`, since widening an `f32` to `f64`
+    // changes its shortest representation.
+    text:      String,
+    policy:    SeparatorPolicy<'a>,
+    max_width: usize,
+    // The index of the next rendering, or `None` once one has fit.
+    next:      Option<usize>,
+}
+
+impl<'a> Iterator for Candidates<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        let index = self.next?;
+        let rendering = match index {
+            0 => self.text.separate_by_policy(self.policy),
+            1 => separate_si(self.value, self.policy),
+            _ => format!("{:.2e}", self.value).separate_by_policy(self.policy),
+        };
+
+        self.next = if index < 2 && rendering.chars().count() > self.max_width {
+            Some(index + 1)
+        } else {
+            None
+        };
+
+        Some(rendering)
+    }
+}
+
+macro_rules! impl_separable_float {
+    ( $( $float:ty ),* ) => {
+        $(
+            impl SeparableFloat for $float {
+                fn separate_percent(&self, decimals: usize, policy: SeparatorPolicy) -> String {
+                    separate_percent(*self as f64, decimals, policy)
+                }
+
+                fn separate_sigfigs(&self, sig: usize, policy: SeparatorPolicy) -> String {
+                    sigfigs(*self as f64, sig).separate_by_policy(policy)
+                }
+
+                fn separate_engineering(&self, policy: SeparatorPolicy) -> String {
+                    if !self.is_finite() {
+                        return self.separate_by_policy(policy);
+                    }
+
+                    let scientific = format!("{:e}", self.abs());
+                    engineering(&scientific, self.is_sign_negative() && *self != 0.0)
+                        .separate_by_policy(policy)
+                }
+
+                fn separate_si(&self, policy: SeparatorPolicy) -> String {
+                    separate_si(*self as f64, policy)
+                }
+
+                fn separate_abbreviated(&self, decimals: usize) -> String {
+                    separate_abbreviated(*self as f64, decimals)
+                }
+
+                fn separate_candidates<'a>(&self, policy: SeparatorPolicy<'a>, max_width: usize)
+                                           -> Candidates<'a> {
+                    Candidates {
+                        value: *self as f64,
+                        text:  self.to_string(),
+                        policy,
+                        max_width,
+                        next:  Some(0),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_separable_float!(f32, f64);
+
+fn separate_percent(value: f64, decimals: usize, policy: SeparatorPolicy) -> String {
+    let text = format!("{:.*}", decimals, value * 100.0);
+    // A small negative number can round to zero.
+    let text = match text.strip_prefix('-') {
+        Some(rest) if rest.chars().all(|c| c == '0' || c == '.') => rest,
+        _                                                        => &text,
+    };
+
+    let mut result = text.separate_by_policy(policy);
+    result.push('%');
+    result
+}
+
+// The SI prefixes from 10⁻³⁰ to 10³⁰, in steps of a thousand.
+const SI_PREFIXES: [&str; 21] = [
+    "q", "r", "y", "z", "a", "f", "p", "n", "µ", "m",
+    "",
+    "k", "M", "G", "T", "P", "E", "Z", "Y", "R", "Q",
+];
+
+fn separate_si(value: f64, policy: SeparatorPolicy) -> String {
+    const SIG: usize = 3;
+
+    if !value.is_finite() || value == 0.0 {
+        return sigfigs(value, SIG).separate_by_policy(policy);
+    }
+
+    // Rounding first finds the exponent that the rounded number will have,
+    // so that 999,999 becomes 1.00 M rather than 1,000 k.
+    let scientific = format!("{:.*e}", SIG - 1, value.abs());
+    let exponent: i32 = scientific[scientific.find('e').unwrap_or(0) + 1 ..].parse().unwrap_or(0);
+
+    let max_step = (SI_PREFIXES.len() / 2) as i32;
+    let step     = exponent.div_euclid(3).max(-max_step).min(max_step);
+    let prefix   = SI_PREFIXES[(step + max_step) as usize];
+
+    let mut result = sigfigs(value / 10f64.powi(3 * step), SIG).separate_by_policy(policy);
+    if !prefix.is_empty() {
+        result.push(' ');
+        result.push_str(prefix);
+    }
+
+    result
+}
+
+// The abbreviations for thousands, millions, billions, and trillions.
+const ABBREVIATIONS: [&str; 5] = ["", "K", "M", "B", "T"];
+
+fn separate_abbreviated(value: f64, decimals: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let scaled = |step: usize| format!("{:.*}", decimals, value / 1000f64.powi(step as i32));
+    let last   = ABBREVIATIONS.len() - 1;
+
+    let mut step = 0;
+    while step < last && value.abs() >= 1000f64.powi(step as i32 + 1) {
+        step += 1;
+    }
+
+    // Rounding can carry the coefficient up to a thousand, as with 999,999
+    // to one decimal place, in which case the next abbreviation fits.
+    let mut coefficient = scaled(step);
+    if step < last {
+        if let Ok(rounded) = coefficient.parse::<f64>() {
+            if rounded.abs() >= 1000.0 {
+                step += 1;
+                coefficient = scaled(step);
+            }
+        }
+    }
+
+    let coefficient = match &coefficient[..] {
+        text if text.contains('.') => text.trim_end_matches('0').trim_end_matches('.'),
+        text                       => text,
+    };
+    // A small negative number can round to zero.
+    let coefficient = if coefficient == "-0" { "0" } else { coefficient };
+
+    let mut result = coefficient.separate_with_commas();
+    result.push_str(ABBREVIATIONS[step]);
+    result
+}
+
+// Converts the scientific notation for a non-negative number, as formatted
+// by `{:e}`, to engineering notation.
+fn engineering(scientific: &str, negative: bool) -> String {
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap_or(0));
+    let mut digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let exponent: isize = exponent[1 ..].parse().unwrap_or(0);
+
+    let eng_exponent = exponent.div_euclid(3) * 3;
+    let int_len      = (exponent - eng_exponent) as usize + 1;
+
+    while digits.len() < int_len {
+        digits.push('0');
+    }
+
+    let (integer, fraction) = digits.split_at(int_len);
+    let eng_exponent = eng_exponent.unsigned_abs().to_string();
+
+    let mut result = String::with_capacity(digits.len() + eng_exponent.len() * 3 + 10);
+
+    if negative {
+        result.push('-');
+    }
+    result.push_str(integer);
+    if !fraction.is_empty() {
+        result.push('.');
+        result.push_str(fraction);
+    }
+    push_power_of_ten(&mut result, exponent < 0, &eng_exponent);
+
+    result
+}
+
+// Formats `value` with `sig` significant figures in positional notation.
+fn sigfigs(value: f64, sig: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+
+    let sig        = sig.max(1);
+    let scientific = format!("{:.*e}", sig - 1, value.abs());
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap_or(0));
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let exponent: isize = exponent[1 ..].parse().unwrap_or(0);
+
+    let mut result = String::with_capacity(sig + exponent.unsigned_abs() + 3);
+
+    if value.is_sign_negative() && value != 0.0 {
+        result.push('-');
+    }
+
+    if exponent < 0 {
+        result.push_str("0.");
+        for _ in 1 .. -exponent {
+            result.push('0');
+        }
+        result.push_str(&digits);
+    } else if (exponent as usize) < sig - 1 {
+        let (integer, fraction) = digits.split_at(exponent as usize + 1);
+        result.push_str(integer);
+        result.push('.');
+        result.push_str(fraction);
+    } else {
+        result.push_str(&digits);
+        for _ in sig - 1 .. exponent as usize {
+            result.push('0');
+        }
+    }
+
+    result
+}
+
+/// Adds separators to the integer part of an already-scaled floating-point
+/// value.
+///
+/// This is meant for composing with humanizing code that picks a unit and
+/// scales the value to it: the caller does the scaling and chooses the unit,
+/// and this function only groups the digits to the left of the decimal point.
+/// The fractional part, sign, and non-finite values such as `NaN` are passed
+/// through as `f64`’s `Display` impl renders them.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{policies, separate_magnitude};
+///
+/// fn humanize_bytes(bytes: f64) -> (f64, &'static str) {
+///     let units = ["B", "KB", "MB", "GB"];
+///     let mut value = bytes;
+///     let mut index = 0;
+///
+///     while value >= 1024.0 && index + 1 < units.len() {
+///         value /= 1024.0;
+///         index += 1;
+///     }
+///
+///     (value, units[index])
+/// }
+///
+/// let (value, unit) = humanize_bytes(1_572_864.0);
+/// assert_eq!( format!("{} {}",
+///                     separate_magnitude(value, policies::COMMA_SEPARATOR), unit),
+///             "1.5 MB" );
+///
+/// let (value, unit) = humanize_bytes(2_684_354_560_000.0);
+/// assert_eq!( format!("{} {}",
+///                     separate_magnitude(value, policies::COMMA_SEPARATOR), unit),
+///             "2,500 GB" );
+/// ```
+pub fn separate_magnitude(value: f64, policy: SeparatorPolicy) -> String {
+    value.separate_by_policy(policy)
+}
+
+/// Formats a column of numbers with `frac_places` digits after the decimal
+/// point, adding separators to their integer parts according to `policy` and
+/// padding them on the left with spaces so that their decimal points line
+/// up.
+///
+/// The integer parts, including any minus signs and separators, are padded
+/// to `int_width` characters, or to the width of the widest one if that is
+/// wider, so no value is truncated.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{policies, separate_column};
+///
+/// let column = separate_column(&[1.5, 1234.5, -12.75], 0, 2, policies::COMMA_SEPARATOR);
+///
+/// assert_eq!( column, [
+///     "    1.50",
+///     "1,234.50",
+///     "  -12.75",
+/// ] );
+/// ```
+pub fn separate_column(values: &[f64], int_width: usize, frac_places: usize,
+                       policy: SeparatorPolicy) -> Vec<String> {
+    // Each value with the length of its integer part, as separated, which
+    // is measured on the output since the policy may change the length of
+    // the fraction, and its separator may be a `.` too.
+    let formatted: Vec<(String, usize)> = values.iter()
+        .map(|value| separate_integer_len(&format!("{:.*}", frac_places, value), policy))
+        .collect();
+
+    let width = formatted.iter().map(|&(_, int_len)| int_len).fold(int_width, usize::max);
+
+    formatted.into_iter()
+        .map(|(s, int_len)| {
+            let padding = width - int_len;
+            let mut result = String::with_capacity(padding + s.len());
+            for _ in 0 .. padding {
+                result.push(' ');
+            }
+            result.push_str(&s);
+            result
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::policies;
+    use super::*;
+
+    #[test]
+    fn magnitude_groups_integer_part() {
+        assert_eq!( separate_magnitude(1234.5, policies::COMMA_SEPARATOR),
+                    "1,234.5" );
+        assert_eq!( separate_magnitude(-1234567.25, policies::SPACE_SEPARATOR),
+                    "-1 234 567.25" );
+        assert_eq!( separate_magnitude(0.125, policies::COMMA_SEPARATOR),
+                    "0.125" );
+    }
+
+    #[test]
+    fn magnitude_non_finite() {
+        assert_eq!( separate_magnitude(f64::NAN, policies::COMMA_SEPARATOR),
+                    "NaN" );
+        assert_eq!( separate_magnitude(f64::NEG_INFINITY, policies::COMMA_SEPARATOR),
+                    "-inf" );
+    }
+
+    #[test]
+    fn percent() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.1234567.separate_percent(2, policy),   "12.35%" );
+        assert_eq!( 1.0.separate_percent(1, policy),         "100.0%" );
+        assert_eq!( 0.0.separate_percent(2, policy),         "0.00%" );
+        assert_eq!( 0.25f32.separate_percent(0, policy),     "25%" );
+    }
+
+    #[test]
+    fn percent_large() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 12.3456.separate_percent(2, policy),     "1,234.56%" );
+        assert_eq!( 98765.4321.separate_percent(1, policy),  "9,876,543.2%" );
+    }
+
+    #[test]
+    fn percent_negative() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-0.1234567).separate_percent(2, policy),  "-12.35%" );
+        assert_eq!( (-12.3456).separate_percent(2, policy),    "-1,234.56%" );
+    }
+
+    #[test]
+    fn percent_negative_zero() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-0.001).separate_percent(0, policy),      "0%" );
+        assert_eq!( (-0.00001).separate_percent(2, policy),    "0.00%" );
+        assert_eq!( (-0.0).separate_percent(1, policy),        "0.0%" );
+        assert_eq!( (-0.001f32).separate_percent(0, policy),   "0%" );
+        assert_eq!( (-0.001).separate_percent(1, policy),      "-0.1%" );
+    }
+
+    #[test]
+    fn sigfigs_across_magnitudes() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1234.5.separate_sigfigs(6, policy),       "1,234.50" );
+        assert_eq!( 1234.5.separate_sigfigs(4, policy),       "1,234" );
+        assert_eq!( 1234.5.separate_sigfigs(2, policy),       "1,200" );
+        assert_eq!( 12.5.separate_sigfigs(6, policy),         "12.5000" );
+        assert_eq!( 0.5.separate_sigfigs(3, policy),          "0.500" );
+        assert_eq!( 0.00012345.separate_sigfigs(3, policy),   "0.000123" );
+        assert_eq!( 123456789.0.separate_sigfigs(9, policy),  "123,456,789" );
+        assert_eq!( 1.5e20.separate_sigfigs(2, policy),
+                    "150,000,000,000,000,000,000" );
+    }
+
+    #[test]
+    fn sigfigs_same_digit_count() {
+        let policy = policies::COMMA_SEPARATOR;
+        let values = [0.001234, 0.5, 1.0, 12.5, 999.9, 1234.5, 98765.4, 123456.7, -3.25];
+
+        for &value in &values {
+            let result = value.separate_sigfigs(6, policy);
+            let digits = result.trim_start_matches(&['-', '0', '.'][..])
+                               .chars().filter(char::is_ascii_digit).count();
+            assert_eq!( digits, 6, "{} as {:?}", value, result );
+        }
+
+        assert_eq!( 12.5.separate_sigfigs(6, policy),         "12.5000" );
+        assert_eq!( 1234.5.separate_sigfigs(6, policy),       "1,234.50" );
+        assert_eq!( 98765.4.separate_sigfigs(6, policy),      "98,765.4" );
+        assert_eq!( 123456.7.separate_sigfigs(6, policy),     "123,457" );
+    }
+
+    #[test]
+    fn si_across_ranges() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1.0.separate_si(policy),                  "1.00" );
+        assert_eq!( 999.0.separate_si(policy),                "999" );
+        assert_eq!( 1234.0.separate_si(policy),               "1.23 k" );
+        assert_eq!( 1234567.0.separate_si(policy),            "1.23 M" );
+        assert_eq!( 45.6e9.separate_si(policy),               "45.6 G" );
+        assert_eq!( 789e12.separate_si(policy),               "789 T" );
+        assert_eq!( 0.5.separate_si(policy),                  "500 m" );
+        assert_eq!( 1.5e-6.separate_si(policy),               "1.50 µ" );
+        assert_eq!( 2e-9.separate_si(policy),                 "2.00 n" );
+    }
+
+    #[test]
+    fn si_rounding() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 999.9.separate_si(policy),                "1.00 k" );
+        assert_eq!( 999_999.0.separate_si(policy),            "1.00 M" );
+        assert_eq!( 0.0009999.separate_si(policy),            "1.00 m" );
+    }
+
+    #[test]
+    fn si_negative() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-1234567.0).separate_si(policy),         "-1.23 M" );
+        assert_eq!( (-0.0025).separate_si(policy),            "-2.50 m" );
+        assert_eq!( (-12.0).separate_si(policy),              "-12.0" );
+    }
+
+    #[test]
+    fn si_beyond_prefixes() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1.5e33.separate_si(policy),               "1,500 Q" );
+        assert_eq!( 2.5e36.separate_si(policy),               "2,500,000 Q" );
+        assert_eq!( 1e-33.separate_si(policy),                "0.00100 q" );
+    }
+
+    #[test]
+    fn abbreviated_across_magnitudes() {
+        assert_eq!( 0.0.separate_abbreviated(1),              "0" );
+        assert_eq!( 999.0.separate_abbreviated(1),            "999" );
+        assert_eq!( 12.345.separate_abbreviated(2),           "12.35" );
+        assert_eq!( 5600.0.separate_abbreviated(1),           "5.6K" );
+        assert_eq!( 1234567.0.separate_abbreviated(1),        "1.2M" );
+        assert_eq!( 1234567.0.separate_abbreviated(3),        "1.235M" );
+        assert_eq!( 3.4e9.separate_abbreviated(1),            "3.4B" );
+        assert_eq!( 7.25e12.separate_abbreviated(2),          "7.25T" );
+        assert_eq!( 1234.0f32.separate_abbreviated(0),        "1K" );
+    }
+
+    #[test]
+    fn abbreviated_boundaries() {
+        assert_eq!( 1000.0.separate_abbreviated(1),           "1K" );
+        assert_eq!( 1e6.separate_abbreviated(2),              "1M" );
+        assert_eq!( 1500.0.separate_abbreviated(0),           "2K" );
+        assert_eq!( 999.96.separate_abbreviated(1),           "1K" );
+        assert_eq!( 999_999.0.separate_abbreviated(1),        "1M" );
+        assert_eq!( 999_949.0.separate_abbreviated(1),        "999.9K" );
+    }
+
+    #[test]
+    fn abbreviated_negative() {
+        assert_eq!( (-5600.0).separate_abbreviated(1),        "-5.6K" );
+        assert_eq!( (-1e9).separate_abbreviated(1),           "-1B" );
+        assert_eq!( (-999_999.0).separate_abbreviated(1),     "-1M" );
+        assert_eq!( (-0.01).separate_abbreviated(1),          "0" );
+        assert_eq!( (-0.4).separate_abbreviated(0),           "0" );
+    }
+
+    #[test]
+    fn abbreviated_beyond_trillions() {
+        assert_eq!( 1.5e15.separate_abbreviated(1),           "1,500T" );
+        assert_eq!( 2e18.separate_abbreviated(0),             "2,000,000T" );
+        assert_eq!( f64::INFINITY.separate_abbreviated(1),    "inf" );
+        assert_eq!( f64::NAN.separate_abbreviated(1),         "NaN" );
+    }
+
+    #[test]
+    fn candidates_fall_back() {
+        let policy = policies::COMMA_SEPARATOR;
+        let all    = |value: f64, width| value.separate_candidates(policy, width)
+                                              .collect::<Vec<_>>();
+
+        assert_eq!( all(1.234e20, 30), ["123,400,000,000,000,000,000"] );
+        assert_eq!( all(1.234e20, 10), ["123,400,000,000,000,000,000", "123 E"] );
+        assert_eq!( all(1.234e20, 4),  ["123,400,000,000,000,000,000", "123 E", "1.23e20"] );
+        assert_eq!( all(1.5e40, 8),    [format!("15{}", ",000".repeat(13)),
+                                        "15,000,000,000 Q".to_owned(), "1.50e40".to_owned()] );
+    }
+
+    #[test]
+    fn candidates_first_fits() {
+        let policy = policies::SPACE_SEPARATOR;
+
+        assert_eq!( 1234567.0.separate_candidates(policy, 9).collect::<Vec<_>>(), ["1 234 567"] );
+        assert_eq!( (-1234567.0).separate_candidates(policy, 9).collect::<Vec<_>>(),
+                    ["-1 234 567", "-1.23 M"] );
+        assert_eq!( 0.0.separate_candidates(policy, 0).count(), 3 );
+    }
+
+    #[test]
+    fn candidates_f32() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1234.1f32.separate_candidates(policy, 10).collect::<Vec<_>>(), ["1,234.1"] );
+        assert_eq!( 0.1f32.separate_candidates(policy, 10).collect::<Vec<_>>(),    ["0.1"] );
+        assert_eq!( 1234567.5f32.separate_candidates(policy, 6).collect::<Vec<_>>(),
+                    ["1,234,567.5", "1.23 M"] );
+    }
+
+    #[test]
+    fn si_edge_cases() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.0.separate_si(policy),                  "0.00" );
+        assert_eq!( f64::INFINITY.separate_si(policy),        "inf" );
+        assert_eq!( 1234.0f32.separate_si(policy),            "1.23 k" );
+    }
+
+    #[test]
+    fn sigfigs_rounding() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 9.999.separate_sigfigs(3, policy),        "10.0" );
+        assert_eq!( 999999.0.separate_sigfigs(3, policy),     "1,000,000" );
+        assert_eq!( 0.0999.separate_sigfigs(1, policy),       "0.1" );
+    }
+
+    #[test]
+    fn sigfigs_edge_cases() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.0.separate_sigfigs(3, policy),          "0.00" );
+        assert_eq!( (-1234.5).separate_sigfigs(5, policy),    "-1,234.5" );
+        assert_eq!( 1234.5.separate_sigfigs(0, policy),       "1,000" );
+        assert_eq!( f64::NAN.separate_sigfigs(3, policy),     "NaN" );
+    }
+
+    #[test]
+    fn engineering_across_magnitudes() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 1.5.separate_engineering(policy),         "1.5 × 10⁰" );
+        assert_eq!( 12.5.separate_engineering(policy),        "12.5 × 10⁰" );
+        assert_eq!( 123.0.separate_engineering(policy),       "123 × 10⁰" );
+        assert_eq!( 1234.0.separate_engineering(policy),      "1.234 × 10³" );
+        assert_eq!( 12345.678.separate_engineering(policy),   "12.345678 × 10³" );
+        assert_eq!( 100000.0.separate_engineering(policy),    "100 × 10³" );
+        assert_eq!( 4.7e9.separate_engineering(policy),       "4.7 × 10⁹" );
+        assert_eq!( 1.0e21.separate_engineering(policy),      "1 × 10²¹" );
+    }
+
+    #[test]
+    fn engineering_small() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.5.separate_engineering(policy),         "500 × 10⁻³" );
+        assert_eq!( 0.0125.separate_engineering(policy),      "12.5 × 10⁻³" );
+        assert_eq!( 2.2e-9.separate_engineering(policy),      "2.2 × 10⁻⁹" );
+    }
+
+    #[test]
+    fn engineering_edge_cases() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( 0.0.separate_engineering(policy),         "0 × 10⁰" );
+        assert_eq!( (-12345.0).separate_engineering(policy),  "-12.345 × 10³" );
+        assert_eq!( 1234.5f32.separate_engineering(policy),   "1.2345 × 10³" );
+        assert_eq!( f64::INFINITY.separate_engineering(policy), "inf" );
+    }
+
+    #[test]
+    fn engineering_grouped_mantissa() {
+        let policy = SeparatorPolicy {
+            groups: &[2],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 123456.0.separate_engineering(policy),    "1,23.456 × 10³" );
+    }
+
+    #[test]
+    fn column() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_column(&[1.5, 1234.5, -12.75], 0, 2, policy), [
+            "    1.50",
+            "1,234.50",
+            "  -12.75",
+        ] );
+        assert_eq!( separate_column(&[1.0, -1234567.0], 0, 1, policy), [
+            "         1.0",
+            "-1,234,567.0",
+        ] );
+        assert_eq!( separate_column(&[7.0, 1234.0], 8, 0, policy), [
+            "       7",
+            "   1,234",
+        ] );
+        assert_eq!( separate_column(&[], 4, 2, policy), Vec::<String>::new() );
+    }
+
+    #[test]
+    fn column_non_finite() {
+        assert_eq!( separate_column(&[f64::NAN, 12.5], 0, 1, policies::SPACE_SEPARATOR), [
+            "NaN",
+            " 12.5",
+        ] );
+    }
+
+    #[test]
+    fn column_dot_separator() {
+        assert_eq!( separate_column(&[1234.5, 1.25], 0, 2, policies::DOT_SEPARATOR), [
+            "1.234.50",
+            "    1.25",
+        ] );
+        assert_eq!( separate_column(&[1234567.0, 12.0], 0, 0, policies::DOT_SEPARATOR), [
+            "1.234.567",
+            "       12",
+        ] );
+    }
+
+    #[test]
+    fn column_shortened_fraction() {
+        let trimmed = SeparatorPolicy {
+            trim_fraction_zeros: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_column(&[1.5, 1234.0], 0, 2, trimmed), [
+            "    1.5",
+            "1,234",
+        ] );
+        assert_eq!( separate_column(&[1.0, 0.0, -0.5], 0, 2, trimmed), [
+            " 1",
+            " 0",
+            "-0.5",
+        ] );
+
+        let truncated = SeparatorPolicy {
+            truncate_fraction: Some(0),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_column(&[1.0, -1234.5], 0, 2, truncated), [
+            "     1",
+            "-1,234",
+        ] );
+    }
+
+    #[test]
+    fn column_fractional_groups() {
+        let policy = SeparatorPolicy {
+            fractional_groups: Some(&[3]),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_column(&[1.5, 1234.0], 0, 6, policy), [
+            "    1.500,000",
+            "1,234.000,000",
+        ] );
+        assert_eq!( separate_column(&[12.25, 1234567.125], 0, 4, policy), [
+            "       12.250,0",
+            "1,234,567.125,0",
+        ] );
+    }
+}
@@ -1,21 +1,48 @@
 use super::SeparatorPolicy;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SeparatorIterator<'a> {
     groups:                  &'a [u8],
     repeat_groups_remaining: usize,
     current_group_index:     usize,
     current_group_size:      usize,
+    last_group_size:         Option<usize>,
+    empty_groups:            usize,
+    pivot_separators:        usize,
     len:                     usize,
 }
 
 impl<'a> SeparatorIterator<'a> {
     pub fn new(policy: &'a SeparatorPolicy, len: usize) -> Self {
-        let groups = &policy.groups;
+        if let Some(pivot) = policy.pivot {
+            if policy.groups.iter().any(|&group| group > 0) {
+                return Self::new_pivot_grouping(policy, pivot, len);
+            }
+        }
+
+        if let Some(first_group) = policy.first_group {
+            return Self::new_left_grouping(policy.groups, first_group as usize, len);
+        }
+
+        Self::new_right_grouping(policy.groups, policy.avoid_orphan, len)
+    }
 
+    fn new_right_grouping(groups: &'a [u8], avoid_orphan: bool, len: usize) -> Self {
+        let mut result = Self::new_grouping(groups, len);
+
+        if avoid_orphan && result.current_group_size == 1 {
+            result.merge_first_group();
+        }
+
+        result
+    }
+
+    fn new_grouping(groups: &'a [u8], len: usize) -> Self {
+        // Empty groups at the right end have no digits to separate.
+        let groups  = &groups[groups.iter().take_while(|&&group| group == 0).count() ..];
         let mut sum = 0;
 
-        for (index, &group) in groups.into_iter().enumerate() {
+        for (index, &group) in groups.iter().enumerate() {
             sum += group as usize;
 
             if len <= sum {
@@ -24,12 +51,28 @@ impl<'a> SeparatorIterator<'a> {
                     repeat_groups_remaining: 0,
                     current_group_index:     index,
                     current_group_size:      len - (sum - group as usize),
+                    last_group_size:         None,
+                    empty_groups:            0,
+                    pivot_separators:        0,
                     len,
                 }
             }
         }
 
         let repeat_group_len = match groups.last() {
+            // An empty repeating group means the rest of the digits stay
+            // together.
+            Some(0) =>
+                return SeparatorIterator {
+                    groups,
+                    repeat_groups_remaining: 0,
+                    current_group_index:     groups.len() - 1,
+                    current_group_size:      len - sum,
+                    last_group_size:         None,
+                    empty_groups:            0,
+                    pivot_separators:        0,
+                    len,
+                },
             Some(n) => *n as usize,
             None    =>
                 return SeparatorIterator {
@@ -37,6 +80,9 @@ impl<'a> SeparatorIterator<'a> {
                     repeat_groups_remaining: 0,
                     current_group_index:     0,
                     current_group_size:      0,
+                    last_group_size:         None,
+                    empty_groups:            0,
+                    pivot_separators:        0,
                     len,
                 }
         };
@@ -50,13 +96,92 @@ impl<'a> SeparatorIterator<'a> {
             repeat_groups_remaining,
             current_group_index: groups.len() - 1,
             current_group_size,
+            last_group_size:     None,
+            empty_groups:        0,
+            pivot_separators:    0,
             len,
         }
     }
 
+    // Groups from the left: `first_group` digits, then groups of the
+    // repeating size, with the remainder in the last group. Without a
+    // repeating size, the rest of the digits form one group. An empty first
+    // group is taken to be of the repeating size.
+    fn new_left_grouping(groups: &'a [u8], first_group: usize, len: usize) -> Self {
+        let repeat_groups = match groups.len() {
+            0 => groups,
+            n => &groups[n - 1 ..],
+        };
+        let repeat_size = repeat_groups.first().map(|&n| n as usize);
+        let first_group = match (first_group, repeat_size) {
+            (0, Some(n)) if n > 0 => n,
+            (0, _)                => len,
+            _                     => first_group,
+        };
+        let len_remaining = len.saturating_sub(first_group);
+
+        let (repeat_groups_remaining, last_group_size) =
+            match repeat_size {
+                _ if len_remaining == 0 => (0, 0),
+                None | Some(0)          => (1, len_remaining),
+                Some(n)                 => ceil_div_mod(len_remaining, n),
+            };
+
+        SeparatorIterator {
+            groups:              repeat_groups,
+            repeat_groups_remaining,
+            current_group_index: 0,
+            current_group_size:  if repeat_groups_remaining == 0 { len } else { first_group },
+            last_group_size:     Some(last_group_size),
+            empty_groups:        0,
+            pivot_separators:    0,
+            len,
+        }
+    }
+
+    // Groups the `pivot` digits to the left of the pivot as usual, and the
+    // digits to its right from the left by the repeating size, with a
+    // boundary at the pivot itself.
+    fn new_pivot_grouping(policy: &'a SeparatorPolicy, pivot: usize, len: usize) -> Self {
+        if pivot == 0 {
+            return Self::new_left_grouping(policy.groups, 0, len);
+        }
+
+        let left_len   = pivot.min(len);
+        let mut result = Self::new_right_grouping(policy.groups, policy.avoid_orphan, left_len);
+        if left_len == len {
+            return result;
+        }
+
+        let right = Self::new_left_grouping(policy.groups, 0, len - pivot);
+
+        result.pivot_separators = 1 + right.sep_len();
+        result.len              = len;
+        result
+    }
+
+    // Merges the most significant group into the next one, if there is one.
+    fn merge_first_group(&mut self) {
+        if let Some(repeat_groups_remaining) = self.repeat_groups_remaining.checked_sub(1) {
+            self.repeat_groups_remaining = repeat_groups_remaining;
+        } else if let Some(current_group_index) = self.current_group_index.checked_sub(1) {
+            self.current_group_index = current_group_index;
+        } else {
+            return;
+        }
+
+        self.current_group_size += self.groups[self.current_group_index] as usize;
+    }
+
     /// How many separators remain?
     pub fn sep_len(&self) -> usize {
-        self.current_group_index + self.repeat_groups_remaining
+        self.current_group_index + self.repeat_groups_remaining + self.pivot_separators
+    }
+
+    /// How many separators go at the boundary just reported: one, plus one
+    /// for each empty group passed over there, unless `collapse` is set.
+    pub fn separators_here(&self, collapse: bool) -> usize {
+        if collapse { 1 } else { 1 + self.empty_groups }
     }
 }
 
@@ -75,12 +200,32 @@ impl<'a> Iterator for SeparatorIterator<'a> {
             self.repeat_groups_remaining = repeat_groups_remaining;
         } else if let Some(current_group_index) = self.current_group_index.checked_sub(1) {
             self.current_group_index = current_group_index;
+        } else if self.pivot_separators > 0 {
+            // Past the pivot, so continue grouping from the left.
+            *self = Self::new_left_grouping(self.groups, 0, self.len);
+            return Some(true);
         } else {
             return Some(false);
         }
 
-        self.current_group_size = self.groups[self.current_group_index] as usize;
-        return Some(true);
+        self.current_group_size = match self.last_group_size {
+            Some(size) if self.repeat_groups_remaining == 0 => size,
+            _ => self.groups[self.current_group_index] as usize,
+        };
+
+        self.empty_groups = 0;
+        while self.current_group_size == 0 && self.repeat_groups_remaining == 0 {
+            match self.current_group_index.checked_sub(1) {
+                Some(current_group_index) => {
+                    self.current_group_index = current_group_index;
+                    self.current_group_size  = self.groups[current_group_index] as usize;
+                    self.empty_groups       += 1;
+                }
+                None => break,
+            }
+        }
+
+        Some(true)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -94,9 +239,13 @@ impl<'a> ExactSizeIterator for SeparatorIterator<'a> {
     }
 }
 
+// Divides `n` by `m`, rounding up, along with the size of the last, possibly
+// short, part, without overflowing for large `n`.
 fn ceil_div_mod(n: usize, m: usize) -> (usize, usize) {
-    let round_up = n + m - 1;
-    (round_up / m, round_up % m + 1)
+    match n % m {
+        0         => (n / m, m),
+        remainder => (n / m + 1, remainder),
+    }
 }
 
 #[cfg(test)]
@@ -104,41 +253,75 @@ mod test_common {
     use super::super::*;
     pub use super::*;
 
-    pub fn make_policy(groups: &[u8]) -> SeparatorPolicy {
+    pub fn make_policy<'a>(groups: &'a [u8]) -> SeparatorPolicy<'a> {
         let mut result = policies::COMMA_SEPARATOR;
         result.groups = groups;
         result
     }
+
+    pub fn group_string(policy: &SeparatorPolicy, digits: &str) -> String {
+        let mut iter   = SeparatorIterator::new(policy, digits.chars().count());
+        let mut result = String::new();
+
+        for digit in digits.chars() {
+            result.push(digit);
+            if iter.next() == Some(true) {
+                for _ in 0 .. iter.separators_here(policy.collapse_separators) {
+                    result.push(',');
+                }
+            }
+        }
+
+        result
+    }
 }
 
 #[cfg(test)]
 mod grouping_test {
     use super::test_common::*;
 
-    fn group_string(groups: &[u8], digits: &str) -> String {
-        use std::iter::once;
-
-        let policy = &make_policy(groups);
-        let iter = SeparatorIterator::new(policy, digits.chars().count());
-
-        digits.chars().zip(iter)
-            .flat_map(|(digit, comma_after)|
-                    once(digit)
-                        .chain(if comma_after { Some(',') } else { None }))
-            .collect()
-    }
-
     macro_rules! grouping_test {
         ( $name:ident, $groups:tt, $result:tt ) => {
             #[test]
             fn $name() {
                 let result = $result;
                 let input = $result.chars().filter(|&c| c != ',').collect::<String>();
-                assert_eq!(group_string(&$groups, &input), result);
+                assert_eq!(group_string(&make_policy(&$groups), &input), result);
             }
         };
     }
 
+    grouping_test!(by_3s_0_2s_of_5, [3, 0, 2], "54,,321");
+    grouping_test!(by_3s_0_2s_of_9, [3, 0, 2], "98,76,54,,321");
+    grouping_test!(by_1s_0_1s_of_3, [1, 0, 1], "3,2,,1");
+    grouping_test!(by_1s_0_0_1s_of_4, [1, 0, 0, 1], "4,3,2,,,1");
+    grouping_test!(by_0_3s_of_7, [0, 3], "7,654,321");
+    grouping_test!(by_0_0_2s_of_5, [0, 0, 2], "5,43,21");
+    grouping_test!(by_0_of_7, [0], "7654321");
+    grouping_test!(by_3s_then_0_of_7, [3, 0], "7654,321");
+    grouping_test!(by_3s_then_0_of_3, [3, 0], "321");
+    grouping_test!(by_3s_0_then_0_of_7, [3, 0, 0], "7654,,321");
+
+    grouping_test!(by_100s_of_1, [100], "1");
+    grouping_test!(by_100s_of_5, [100], "54321");
+    grouping_test!(by_255_then_1s_of_3, [255, 1], "321");
+
+    #[test]
+    fn group_larger_than_number() {
+        let policy = make_policy(&[100]);
+
+        for &len in &[0, 1, 5, 99, 100] {
+            let iter = SeparatorIterator::new(&policy, len);
+            assert_eq!( iter.sep_len(), 0 );
+            assert_eq!( iter.filter(|&b| b).count(), 0 );
+        }
+
+        let digits: String = (0 .. 101).map(|i| (b'0' + i % 10) as char).collect();
+        let result = group_string(&policy, &digits);
+        assert_eq!( result.find(','), Some(1) );
+        assert_eq!( result.len(), 102 );
+    }
+
     grouping_test!(by_nothing_of_0, [], "");
     grouping_test!(by_nothing_of_1, [], "1");
     grouping_test!(by_nothing_of_2, [], "21");
@@ -198,6 +381,224 @@ mod grouping_test {
                    "");
 }
 
+#[cfg(test)]
+mod increasing_test {
+    use super::test_common::*;
+    use super::super::SeparatorPolicy;
+
+    fn increasing_string(digits: &str) -> String {
+        let mut groups = Vec::new();
+        group_string(&SeparatorPolicy::increasing(1, 1, ",", &mut groups), digits)
+    }
+
+    #[test]
+    fn matches_explicit_groups() {
+        for &len in &[0, 1, 7, 9, 10, 11, 16, 20] {
+            let digits: String = "KJIHGFEDCBA987654321".chars().skip(20 - len).collect();
+
+            assert_eq!( increasing_string(&digits),
+                        group_string(&make_policy(&[1, 2, 3, 4, 5]), &digits) );
+        }
+    }
+
+    #[test]
+    fn long_digit_run() {
+        let digits = "9".repeat(28);
+
+        assert_eq!( increasing_string(&digits),
+                    "9999999,999999,99999,9999,999,99,9" );
+    }
+
+    #[test]
+    fn generated_groups() {
+        let mut groups = Vec::new();
+
+        assert_eq!( SeparatorPolicy::increasing(1, 1, ",", &mut groups).groups.len(), 255 );
+        assert_eq!( SeparatorPolicy::increasing(100, 50, ",", &mut groups).groups,
+                    &[100, 150, 200, 250] );
+        assert_eq!( SeparatorPolicy::increasing(3, 0, ",", &mut groups).groups, &[3] );
+        assert_eq!( SeparatorPolicy::increasing(0, 1, ",", &mut groups).groups, &[] );
+    }
+
+    #[test]
+    fn reuses_buffer() {
+        let mut groups = vec![9, 9, 9, 9, 9, 9];
+
+        assert_eq!( SeparatorPolicy::increasing(2, 3, ",", &mut groups).groups.len(), 85 );
+        assert_eq!( &groups[.. 3], &[2, 5, 8] );
+    }
+}
+
+#[cfg(test)]
+mod pattern_test {
+    use super::test_common::*;
+    use super::super::{PatternError, SeparatorPolicy};
+
+    fn pattern_groups(pattern: &str) -> Result<(String, Vec<u8>), PatternError> {
+        let mut groups = Vec::new();
+        let separator = SeparatorPolicy::from_pattern(pattern, &mut groups)?.separator.to_owned();
+        Ok((separator, groups))
+    }
+
+    #[test]
+    fn repeating() {
+        assert_eq!( pattern_groups("#,###,..."),    Ok((",".to_owned(), vec![3])) );
+        assert_eq!( pattern_groups("#,##,###,..."), Ok((",".to_owned(), vec![3, 2])) );
+        assert_eq!( pattern_groups("#,###..."),     Ok((",".to_owned(), vec![3])) );
+        assert_eq!( pattern_groups("0 0000..."),    Ok((" ".to_owned(), vec![4])) );
+    }
+
+    #[test]
+    fn not_repeating() {
+        assert_eq!( pattern_groups("#,###"),        Ok((",".to_owned(), vec![3, 0])) );
+        assert_eq!( pattern_groups("##'##'###"),    Ok(("'".to_owned(), vec![3, 2, 0])) );
+        assert_eq!( pattern_groups("#\u{202F}###"), Ok(("\u{202F}".to_owned(), vec![3, 0])) );
+    }
+
+    #[test]
+    fn groups_digits() {
+        let mut groups = Vec::new();
+        let indian = SeparatorPolicy::from_pattern("#,##,###,...", &mut groups).unwrap();
+        assert_eq!( group_string(&indian, "123456789"), "12,34,56,789" );
+
+        let mut groups = Vec::new();
+        let once = SeparatorPolicy::from_pattern("#,##,###", &mut groups).unwrap();
+        assert_eq!( group_string(&once, "123456789"), "1234,56,789" );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!( pattern_groups("####"),         Err(PatternError::NoSeparator) );
+        assert_eq!( pattern_groups("####..."),      Err(PatternError::NoSeparator) );
+        assert_eq!( pattern_groups("#,###.###"),    Err(PatternError::MixedSeparators) );
+        assert_eq!( pattern_groups("#,###...."),    Err(PatternError::MixedSeparators) );
+        assert_eq!( pattern_groups(",###"),         Err(PatternError::MissingDigits) );
+        assert_eq!( pattern_groups("#,###,"),       Err(PatternError::MissingDigits) );
+        assert_eq!( pattern_groups(""),             Err(PatternError::MissingDigits) );
+        assert_eq!( pattern_groups(&format!("#,{}", "#".repeat(256))),
+                    Err(PatternError::GroupTooLong) );
+    }
+}
+
+#[cfg(test)]
+mod orphan_test {
+    use super::test_common::*;
+
+    fn orphan_string(groups: &[u8], digits: &str) -> String {
+        let mut policy = make_policy(groups);
+        policy.avoid_orphan = true;
+        group_string(&policy, digits)
+    }
+
+    #[test]
+    fn merges_orphan() {
+        assert_eq!( orphan_string(&[3], "1234"),          "1234" );
+        assert_eq!( orphan_string(&[3], "1234567"),       "1234,567" );
+        assert_eq!( orphan_string(&[3, 2], "123456"),     "123,456" );
+        assert_eq!( orphan_string(&[1, 2, 3], "1234567"), "1234,56,7" );
+    }
+
+    #[test]
+    fn keeps_non_orphan() {
+        assert_eq!( orphan_string(&[3], ""),              "" );
+        assert_eq!( orphan_string(&[3], "1"),             "1" );
+        assert_eq!( orphan_string(&[3], "123"),           "123" );
+        assert_eq!( orphan_string(&[3], "12345"),         "12,345" );
+        assert_eq!( orphan_string(&[3], "12345678"),      "12,345,678" );
+        assert_eq!( orphan_string(&[3, 2], "1234567"),    "12,34,567" );
+        assert_eq!( orphan_string(&[], "1234567"),        "1234567" );
+    }
+
+    #[test]
+    fn sep_len_after_merge() {
+        let mut policy = make_policy(&[3]);
+        policy.avoid_orphan = true;
+
+        let iter = SeparatorIterator::new(&policy, 10);
+        assert_eq!( iter.sep_len(), 2 );
+        assert_eq!( iter.filter(|&b| b).count(), 2 );
+    }
+}
+
+#[cfg(test)]
+mod collapse_test {
+    use super::test_common::*;
+
+    fn collapsed_string(groups: &[u8], digits: &str) -> String {
+        let mut policy = make_policy(groups);
+        policy.collapse_separators = true;
+        group_string(&policy, digits)
+    }
+
+    #[test]
+    fn collapses_empty_groups() {
+        assert_eq!( collapsed_string(&[3, 0, 3], "1234567"),       "1,234,567" );
+        assert_eq!( collapsed_string(&[1, 0, 0, 1], "1234"),       "1,2,3,4" );
+        assert_eq!( collapsed_string(&[3, 0, 2], "123456789"),     "12,34,56,789" );
+    }
+
+    #[test]
+    fn nothing_to_collapse() {
+        assert_eq!( collapsed_string(&[3], "1234567"),             "1,234,567" );
+        assert_eq!( collapsed_string(&[3, 0], "1234567"),          "1234,567" );
+        assert_eq!( collapsed_string(&[], "1234567"),              "1234567" );
+    }
+}
+
+#[cfg(test)]
+mod left_grouping_test {
+    use super::test_common::*;
+
+    fn left_string(first_group: u8, groups: &[u8], digits: &str) -> String {
+        let mut policy = make_policy(groups);
+        policy.first_group = Some(first_group);
+        group_string(&policy, digits)
+    }
+
+    #[test]
+    fn first_group_then_repeat() {
+        assert_eq!( left_string(1, &[4], "A12345678"),  "A,1234,5678" );
+        assert_eq!( left_string(1, &[4], "A1234567"),   "A,1234,567" );
+        assert_eq!( left_string(1, &[4], "A12345"),     "A,1234,5" );
+        assert_eq!( left_string(2, &[3], "AB123456"),   "AB,123,456" );
+        assert_eq!( left_string(3, &[2, 4], "1234567"), "123,4567" );
+    }
+
+    #[test]
+    fn short_input() {
+        assert_eq!( left_string(1, &[4], ""),           "" );
+        assert_eq!( left_string(1, &[4], "A"),          "A" );
+        assert_eq!( left_string(3, &[4], "AB"),         "AB" );
+        assert_eq!( left_string(1, &[4], "A1"),         "A,1" );
+    }
+
+    #[test]
+    fn no_repeat() {
+        assert_eq!( left_string(1, &[], "A1234"),       "A,1234" );
+        assert_eq!( left_string(1, &[0], "A1234"),      "A,1234" );
+        assert_eq!( left_string(0, &[3], "1234567"),    "123,456,7" );
+        assert_eq!( left_string(0, &[], "1234567"),     "1234567" );
+    }
+
+    #[test]
+    fn sep_len() {
+        let mut policy = make_policy(&[4]);
+        policy.first_group = Some(1);
+
+        for len in 0 .. 12 {
+            let mut iter = SeparatorIterator::new(&policy, len);
+            let mut predicted = iter.sep_len();
+            while let Some(comma_after) = iter.next() {
+                if comma_after {
+                    predicted -= 1;
+                }
+                assert_eq!( iter.sep_len(), predicted );
+            }
+            assert_eq!( predicted, 0 );
+        }
+    }
+}
+
 #[cfg(test)]
 mod sep_len_test {
     use super::test_common::*;
@@ -212,7 +613,7 @@ mod sep_len_test {
             iter.next()
         } {
             predictions.push(prediction);
-            actuals.push(if actual { 1 } else { 0 })
+            actuals.push(if actual { iter.separators_here(false) } else { 0 })
         }
 
         let mut acc = 0;
@@ -240,6 +641,13 @@ mod sep_len_test {
 
     run_down!(by_nothing_of_10, [], 10);
 
+    run_down!(by_3s_0_2s_of_10, [3, 0, 2], 10);
+    run_down!(by_3s_0_2s_of_5, [3, 0, 2], 5);
+    run_down!(by_3s_0_2s_of_3, [3, 0, 2], 3);
+    run_down!(by_1s_0_0_1s_of_6, [1, 0, 0, 1], 6);
+    run_down!(by_0_3s_of_7, [0, 3], 7);
+    run_down!(by_3s_then_0_of_10, [3, 0], 10);
+
     run_down!(by_3s_of_10, [3], 10);
 
     run_down!(by_2s_of_10, [2], 10);
@@ -266,3 +674,80 @@ mod sep_len_test {
     run_down!(by_1s23_of_1, [3, 2, 1], 1);
     run_down!(by_1s23_of_0, [3, 2, 1], 0);
 }
+
+#[cfg(test)]
+mod ceil_div_mod_test {
+    use super::ceil_div_mod;
+
+    #[test]
+    fn small() {
+        assert_eq!( ceil_div_mod(0, 3), (0, 3) );
+        assert_eq!( ceil_div_mod(1, 3), (1, 1) );
+        assert_eq!( ceil_div_mod(3, 3), (1, 3) );
+        assert_eq!( ceil_div_mod(7, 3), (3, 1) );
+    }
+
+    #[test]
+    fn no_overflow() {
+        assert_eq!( ceil_div_mod(usize::MAX, 1),   (usize::MAX, 1) );
+        assert_eq!( ceil_div_mod(usize::MAX, 255), (usize::MAX / 255, 255) );
+        assert_eq!( ceil_div_mod(usize::MAX, 2),   (usize::MAX / 2 + 1, 1) );
+    }
+
+    #[test]
+    fn largest_digit_counts() {
+        use super::super::{SeparatorPolicy, policies};
+        use super::SeparatorIterator;
+
+        let policy = |groups| SeparatorPolicy { groups, .. policies::COMMA_SEPARATOR };
+
+        assert_eq!( SeparatorIterator::new(&policy(&[1]), usize::MAX).sep_len(),
+                    usize::MAX - 1 );
+        assert_eq!( SeparatorIterator::new(&policy(&[3]), usize::MAX).sep_len(),
+                    (usize::MAX - 1) / 3 );
+        assert_eq!( SeparatorIterator::new(&policy(&[3, 2]), usize::MAX).sep_len(),
+                    1 + (usize::MAX - 4) / 2 );
+        assert_eq!( SeparatorIterator::new(&policy(&[255]), usize::MAX).sep_len(),
+                    usize::MAX / 255 - 1 );
+    }
+}
+
+#[cfg(test)]
+mod pivot_test {
+    use super::test_common::*;
+
+    fn pivot_string(groups: &[u8], pivot: usize, digits: &str) -> String {
+        let mut policy = make_policy(groups);
+        policy.pivot = Some(pivot);
+        group_string(&policy, digits)
+    }
+
+    #[test]
+    fn groups_both_ways() {
+        assert_eq!( pivot_string(&[2], 4, "123456789"),        "12,34,56,78,9" );
+        assert_eq!( pivot_string(&[3], 4, "123456789"),        "1,234,567,89" );
+        assert_eq!( pivot_string(&[3, 2], 5, "1234567890"),    "12,345,67,89,0" );
+        assert_eq!( pivot_string(&[3, 0], 4, "123456789"),     "1,234,56789" );
+    }
+
+    #[test]
+    fn pivot_at_ends() {
+        assert_eq!( pivot_string(&[2], 0, "12345"),            "12,34,5" );
+        assert_eq!( pivot_string(&[2], 5, "12345"),            "1,23,45" );
+        assert_eq!( pivot_string(&[2], 9, "12345"),            "1,23,45" );
+        assert_eq!( pivot_string(&[2], 4, "12345"),            "12,34,5" );
+        assert_eq!( pivot_string(&[2], 1, "12345"),            "1,23,45" );
+        assert_eq!( pivot_string(&[], 2, "12345"),             "12345" );
+        assert_eq!( pivot_string(&[2], 2, ""),                 "" );
+    }
+
+    #[test]
+    fn sep_len_counts_both_sides() {
+        let mut policy = make_policy(&[2]);
+        policy.pivot = Some(4);
+
+        let iter = SeparatorIterator::new(&policy, 9);
+        assert_eq!( iter.sep_len(), 4 );
+        assert_eq!( iter.filter(|&b| b).count(), 4 );
+    }
+}
@@ -0,0 +1,197 @@
+use super::{OnMalformed, Separable, SeparatorPolicy};
+use super::display::find_span;
+use super::helpers::SeparatorIterator;
+use super::stream::keeps_after;
+
+/// Adds separators to the string `s` according to `policy`, in place.
+///
+/// The string is grown once, to make room for all the separators, and then
+/// the digits are moved into place in a single pass, so this takes linear
+/// time. (Inserting each separator with `String::insert_str` would instead
+/// shift the rest of the string every time, taking quadratic time for long
+/// numbers.)
+///
+/// The result is the same as that of [`separate_by_policy`]. When `policy`
+/// calls for more than inserting separators, such as dropping ignorable
+/// characters or padding the fraction, this falls back to formatting into a
+/// new string.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{policies, separate_in_place};
+///
+/// let mut s = String::from("-1234567.5");
+/// separate_in_place(&mut s, policies::COMMA_SEPARATOR);
+/// assert_eq!( s, "-1,234,567.5" );
+/// ```
+///
+/// [`separate_by_policy`]: trait.Separable.html#tymethod.separate_by_policy
+pub fn separate_in_place(s: &mut String, policy: SeparatorPolicy) {
+    let (start, limit, count) = {
+        let (before, number, _, count) =
+            find_span(s, |c| policy.is_digit(c), |c| policy.is_ignorable(c));
+
+        if number.chars().count() != count
+            || !policy.only_inserts_separators()
+            || !keeps_after(&policy)
+            || policy.on_malformed != OnMalformed::GroupFirst
+            || policy.collapse_separators {
+
+            *s = s.separate_by_policy(policy);
+            return;
+        }
+
+        (before.len(), before.len() + number.len(), count)
+    };
+
+    let mut iter = SeparatorIterator::new(&policy, count);
+    let extra = (0 .. iter.sep_len())
+        .map(|boundary| policy.separator_at(boundary).len() + policy.joiner().len())
+        .sum::<usize>();
+
+    if extra == 0 {
+        return;
+    }
+
+    let mut bytes = ::std::mem::take(s).into_bytes();
+    let old_len   = bytes.len();
+
+    // Move the number and everything after it to the end, then move the
+    // digits back toward the front one at a time, leaving gaps for the
+    // separators. The write position never passes the read position, so no
+    // digit is overwritten before it’s moved.
+    bytes.resize(old_len + extra, 0);
+    bytes.copy_within(start .. old_len, start + extra);
+
+    let mut read     = start + extra;
+    let mut write    = start;
+    let mut boundary = iter.sep_len();
+
+    while let Some(comma_after) = iter.next() {
+        let len = utf8_len(bytes[read]);
+        bytes.copy_within(read .. read + len, write);
+        read  += len;
+        write += len;
+
+        if comma_after {
+            for _ in 0 .. iter.separators_here(false) {
+                boundary -= 1;
+                for separator in &[policy.separator_at(boundary), policy.joiner()] {
+                    let separator = separator.as_bytes();
+                    bytes[write .. write + separator.len()].copy_from_slice(separator);
+                    write += separator.len();
+                }
+            }
+        }
+    }
+
+    debug_assert_eq!( read, limit + extra );
+    debug_assert_eq!( write, read );
+
+    *s = String::from_utf8(bytes).expect("separate_in_place: broke UTF-8");
+}
+
+// The length of the UTF-8 encoded character that starts with the given byte.
+fn utf8_len(first_byte: u8) -> usize {
+    match first_byte.leading_ones() {
+        0 => 1,
+        n => n as usize,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::*;
+
+    fn check(input: &str, policy: SeparatorPolicy) {
+        let mut s = input.to_owned();
+        separate_in_place(&mut s, policy);
+        assert_eq!( s, input.separate_by_policy(policy) );
+    }
+
+    #[test]
+    fn in_place() {
+        let mut s = String::from("1234567");
+        separate_in_place(&mut s, policies::COMMA_SEPARATOR);
+        assert_eq!( s, "1,234,567" );
+    }
+
+    #[test]
+    fn in_place_matches_fresh() {
+        for input in &["", "1", "123", "1234", "-1234567.891", "≈ 9876543210 items",
+                       "no digits", "1234567890123456789012345678901234567890"] {
+            check(input, policies::COMMA_SEPARATOR);
+            check(input, policies::HEX_FOUR);
+        }
+    }
+
+    #[test]
+    fn in_place_multibyte() {
+        let policy = SeparatorPolicy {
+            separator: "😃",
+            groups:    &[1, 2],
+            digits:    &['🙁', '٣'],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("→🙁٣🙁٣🙁٣🙁←", policy);
+
+        let policy = SeparatorPolicy {
+            separators_after: &[(1, "\u{202F}"), (2, "")],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1234567890", policy);
+
+        let policy = SeparatorPolicy {
+            bind_with_zwj: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        check("-1234567.5", policy);
+
+        let policy = SeparatorPolicy {
+            groups:           &[3, 0, 2],
+            separators_after: &[(1, "'")],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1234567890", policy);
+    }
+
+    #[test]
+    fn in_place_fallback() {
+        let policy = SeparatorPolicy {
+            ignorable:    &[' '],
+            fraction_pad: Some((2, '0')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1 234 567.8", policy);
+
+        let policy = SeparatorPolicy {
+            regroup: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1,23,456", policy);
+
+        let policy = SeparatorPolicy {
+            groups:              &[3, 0, 3],
+            collapse_separators: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1234567", policy);
+
+        let policy = SeparatorPolicy {
+            on_malformed: OnMalformed::Passthrough,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        check("1234.5.6", policy);
+        check("1234.5", policy);
+    }
+}
@@ -0,0 +1,84 @@
+use std::ops::{Bound, RangeBounds};
+
+use super::{Separable, SeparatorPolicy};
+
+/// Adds separators, according to `policy`, to both endpoints of `range`,
+/// joining them with `sep`, such as `".."`.
+///
+/// This works for exclusive and inclusive ranges alike, since `sep` is
+/// written as given; pass `"..="` to show an inclusive range the way Rust
+/// writes it. A range that is unbounded at either end leaves that endpoint
+/// out.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{policies, separate_range};
+///
+/// assert_eq!( separate_range(1000 .. 2000000, policies::COMMA_SEPARATOR, ".."),
+///             "1,000..2,000,000" );
+/// assert_eq!( separate_range(1000 ..= 9999, policies::SPACE_SEPARATOR, " – "),
+///             "1 000 – 9 999" );
+/// assert_eq!( separate_range(10000 .., policies::COMMA_SEPARATOR, ".."),
+///             "10,000.." );
+/// ```
+pub fn separate_range<T, R>(range: R, policy: SeparatorPolicy, sep: &str) -> String
+where T: Separable,
+      R: RangeBounds<T> {
+
+    let mut result = endpoint(range.start_bound(), policy);
+    result.push_str(sep);
+    result.push_str(&endpoint(range.end_bound(), policy));
+    result
+}
+
+// The separated value of an endpoint, or nothing if it is unbounded.
+fn endpoint<T: Separable>(bound: Bound<&T>, policy: SeparatorPolicy) -> String {
+    match bound {
+        Bound::Included(value) | Bound::Excluded(value) => value.separate_by_policy(policy),
+        Bound::Unbounded                                 => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{SeparatorPolicy, policies};
+    use super::separate_range;
+
+    #[test]
+    fn exclusive() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_range(1000 .. 2000000, policy, ".."),    "1,000..2,000,000" );
+        assert_eq!( separate_range(-12345 .. 0, policy, ".."),        "-12,345..0" );
+        assert_eq!( separate_range(1.5 .. 12345.25, policy, " to "),  "1.5 to 12,345.25" );
+    }
+
+    #[test]
+    fn inclusive() {
+        let policy = policies::SPACE_SEPARATOR;
+
+        assert_eq!( separate_range(1000 ..= 2000000, policy, "..="),  "1 000..=2 000 000" );
+        assert_eq!( separate_range(0u8 ..= 255, policy, "–"),         "0–255" );
+    }
+
+    #[test]
+    fn unbounded() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( separate_range(1000000 .., policy, ".."),         "1,000,000.." );
+        assert_eq!( separate_range(.. 1000000, policy, ".."),         "..1,000,000" );
+        assert_eq!( separate_range(..= 1000000, policy, "..="),       "..=1,000,000" );
+        assert_eq!( separate_range::<i32, _>(.., policy, ".."),       ".." );
+    }
+
+    #[test]
+    fn other_policies() {
+        let policy = SeparatorPolicy {
+            groups: &[3, 2],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( separate_range(100000 .. 10000000, policy, ".."), "1,00,000..1,00,00,000" );
+    }
+}
@@ -24,17 +24,62 @@
 //! However, it's also possible to pass a policy for different behavior:
 //!
 //! ```
-//! use thousands::{Separable, SeparatorPolicy, digits};
+//! use thousands::{Separable, SeparatorPolicy, digits, policies};
 //!
 //! let policy = SeparatorPolicy {
 //!     separator: ",",
 //!     groups:    &[3, 2],
 //!     digits:    digits::ASCII_DECIMAL,
+//!     .. policies::COMMA_SEPARATOR
 //! };
 //!
 //! assert_eq!( 1234567890.separate_by_policy(policy), "1,23,45,67,890" );
 //! ```
 //!
+//! Any integer type works, including `u128` and `i128`, so large counts
+//! such as nanosecond durations can be grouped for logs. With `chrono`,
+//! format `duration.num_nanoseconds()`, which is an `Option<i64>`; with the
+//! standard library, format `Duration::as_nanos`, which is a `u128`:
+//!
+//! ```
+//! use std::time::Duration;
+//! use thousands::Separable;
+//!
+//! let elapsed = Duration::new(1, 234_567_890);
+//!
+//! assert_eq!( format!("{} ns", elapsed.as_nanos().separate_with_commas()),
+//!             "1,234,567,890 ns" );
+//! ```
+//!
+//! # Locale data
+//!
+//! This crate doesn’t bundle locale data. To follow a locale’s conventions
+//! as given by CLDR, for example via the `icu_decimal` crate, look up the
+//! locale’s grouping separator and its primary and secondary grouping sizes,
+//! and put them in a policy. The primary size is the size of the rightmost
+//! group and the secondary size is the size of all the others, so they become
+//! `groups: &[primary, secondary]`. For `en-IN`, the separator is `","`, the
+//! primary size is 3, and the secondary size is 2:
+//!
+//! ```
+//! use thousands::{Separable, SeparatorPolicy, policies};
+//!
+//! let (separator, primary, secondary) = (",", 3, 2);
+//!
+//! let en_in = SeparatorPolicy {
+//!     separator,
+//!     groups: &[primary, secondary],
+//!     .. policies::COMMA_SEPARATOR
+//! };
+//!
+//! assert_eq!( 12345678.separate_by_policy(en_in), "1,23,45,678" );
+//! ```
+//!
+//! A locale that doesn’t group digits at all corresponds to `groups: &[]`.
+//!
+//! With the optional `icu` feature, [`LocaleGrouping`] looks these up in the
+//! CLDR data of the `icu_decimal` crate and makes the policy.
+//!
 //! # Usage
 //!
 //! It’s [on crates.io](https://crates.io/crates/thousands), so you can add
@@ -46,7 +91,13 @@
 //!
 //! to your `Cargo.toml`.
 //!
-//! This crate supports Rust version 1.22 and newer.
+//! This crate supports Rust version 1.60 and newer.
+//!
+//! The optional `heapless` feature adds [`separate_heapless`], which returns
+//! a fixed-capacity `heapless::String`.
+//!
+//! The optional `icu` feature adds [`LocaleGrouping`], as described above.
+//! It needs Rust 1.67 or newer, as the `icu_decimal` crate does.
 //!
 //! [`Separable`]: trait.Separable.html
 //! [`SeparatorPolicy`]: struct.SeparatorPolicy.html
@@ -54,6 +105,17 @@
 //! [`separate_with_spaces`]: trait.Separable.html#method.separate_with_spaces
 //! [`separate_with_dots`]: trait.Separable.html#method.separate_with_dots
 //! [`separate_with_underscores`]: trait.Separable.html#method.separate_with_underscores
+//! [`separate_heapless`]: trait.Separable.html#method.separate_heapless
+//! [`LocaleGrouping`]: struct.LocaleGrouping.html
+
+#[cfg(feature = "heapless")]
+extern crate heapless;
+#[cfg(feature = "icu")]
+extern crate icu_decimal;
+#[cfg(feature = "icu")]
+extern crate icu_locid;
+#[cfg(feature = "icu")]
+extern crate icu_provider;
 
 /// Collections of digits.
 ///
@@ -62,12 +124,91 @@ pub mod digits;
 
 /// Predefined policies.
 pub mod policies;
-pub use policies::SeparatorPolicy;
+pub use policies::{CodePlacement, OnMalformed, SeparatorPolicy};
 
 mod traits;
 pub use traits::Separable;
 
+// Sharing named policies across a program.
+mod registry;
+pub use registry::{policy_by_name, register_policy};
+
+mod errors;
+pub use errors::{NonAsciiSeparator, PatternError, SeparateError, UnexpectedChar};
+
 // Contains blanket impl<T: Display> Separable for T.
 mod display;
 
 mod helpers;
+
+// Separating ASCII text a byte at a time.
+mod ascii;
+
+// Walking the pieces of a separated number, for building output of any kind.
+mod stream;
+
+// Splits fractions and exponents off of the text following a number’s
+// integer digits.
+mod exponent;
+
+// Inserting separators into an existing `String`.
+mod in_place;
+pub use in_place::separate_in_place;
+
+// Converting between policies’ formats.
+mod reformat;
+pub use reformat::reformat;
+
+// Reporting the pieces of a separated number.
+mod visit;
+pub use visit::GroupEvent;
+
+// Displaying values with separators without building a string.
+mod separated;
+pub use separated::{Separated, debug_separated};
+
+// Locating the digits and separators of separated numbers.
+mod ranges;
+pub use ranges::RangeKind;
+
+// Separated numbers with their widths.
+mod measured;
+pub use measured::Measured;
+
+// Splitting separated numbers into sign, integer, and fraction.
+mod parts;
+pub use parts::SeparatedParts;
+
+// Separating changes in value with their signs.
+mod delta;
+pub use delta::Sign;
+
+// Laying out digits according to templates.
+mod templates;
+pub use templates::{format_kv, format_template, format_with_separators};
+
+// Formatting characters’ code points.
+mod chars;
+pub use chars::separate_codepoint;
+
+// Separating with separators chosen by place value.
+mod places;
+pub use places::separate_by_value_fn;
+
+// Formatting the endpoints of ranges.
+mod intervals;
+pub use intervals::separate_range;
+
+// Looking up locales’ grouping in ICU data.
+#[cfg(feature = "icu")]
+mod locale;
+#[cfg(feature = "icu")]
+pub use locale::LocaleGrouping;
+
+// Formatting byte data.
+mod bytes;
+pub use bytes::{separate_bytes, separate_hex_bytes};
+
+// Helpers for formatting floating-point values.
+mod floats;
+pub use floats::{Candidates, SeparableFloat, separate_column, separate_magnitude};
@@ -0,0 +1,144 @@
+use icu_decimal::provider::{Baked, DecimalSymbolsV1Marker};
+use icu_locid::Locale;
+use icu_provider::{DataError, DataLocale, DataProvider, DataRequest};
+
+use super::{SeparatorPolicy, policies};
+
+/// The digit grouping of a locale, as given by the CLDR data compiled into
+/// the `icu_decimal` crate.
+///
+/// This needs the `icu` feature, which needs Rust 1.67 or newer. Only the
+/// grouping separator, the group sizes, and the fewest digits that are
+/// grouped come from the locale. The digits and the decimal point of the
+/// [`policy`] are those of [`COMMA_SEPARATOR`], since they describe the text
+/// being separated, which Rust formats with ASCII digits and a `.`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "icu")] {
+/// extern crate icu_locid;
+/// # extern crate thousands;
+///
+/// use thousands::{LocaleGrouping, Separable};
+///
+/// let locale: icu_locid::Locale = "en-IN".parse().unwrap();
+/// let en_in  = LocaleGrouping::load(&locale).unwrap();
+///
+/// assert_eq!( 12345678.separate_by_policy(en_in.policy()), "1,23,45,678" );
+/// # }
+/// ```
+///
+/// [`policy`]: #method.policy
+/// [`COMMA_SEPARATOR`]: policies/constant.COMMA_SEPARATOR.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocaleGrouping {
+    separator:  String,
+    groups:     [u8; 2],
+    len:        usize,
+    min_digits: usize,
+}
+
+impl LocaleGrouping {
+    /// Looks up the grouping of `locale`, falling back to a more general
+    /// locale, and finally to the root locale, when there’s no data for it.
+    pub fn load(locale: &Locale) -> Result<Self, DataError> {
+        let locale  = DataLocale::from(locale);
+        let request = DataRequest { locale: &locale, metadata: Default::default() };
+        let payload = DataProvider::<DecimalSymbolsV1Marker>::load(&Baked, request)?
+            .take_payload()?;
+        let symbols = payload.get();
+        let sizes   = &symbols.grouping_sizes;
+
+        // A secondary size of 0 means that every group has the primary size.
+        let (groups, len) = match (sizes.primary, sizes.secondary) {
+            (0, _)                                   => ([0, 0], 0),
+            (primary, secondary) if secondary == 0
+                                 || secondary == primary => ([primary, 0], 1),
+            (primary, secondary)                     => ([primary, secondary], 2),
+        };
+
+        // CLDR counts the digits that must come before the first separator.
+        let min_digits = match sizes.min_grouping {
+            0 | 1 => 0,
+            min   => sizes.primary as usize + min as usize,
+        };
+
+        Ok(LocaleGrouping {
+            separator: symbols.grouping_separator.to_string(),
+            groups,
+            len,
+            min_digits,
+        })
+    }
+
+    /// The locale’s grouping separator.
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// The locale’s group sizes, from the right, as in
+    /// [`SeparatorPolicy::groups`](struct.SeparatorPolicy.html#structfield.groups).
+    pub fn groups(&self) -> &[u8] {
+        &self.groups[.. self.len]
+    }
+
+    /// A policy that groups decimal digits as the locale does.
+    pub fn policy(&self) -> SeparatorPolicy<'_> {
+        SeparatorPolicy {
+            separator:  &self.separator,
+            groups:     self.groups(),
+            min_digits: self.min_digits,
+            .. policies::COMMA_SEPARATOR
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use icu_locid::Locale;
+
+    use super::LocaleGrouping;
+    use super::super::Separable;
+
+    fn load(locale: &str) -> LocaleGrouping {
+        LocaleGrouping::load(&locale.parse::<Locale>().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn en_in() {
+        let en_in = load("en-IN");
+
+        assert_eq!( en_in.separator(),                               "," );
+        assert_eq!( en_in.groups(),                                  &[3, 2] );
+        assert_eq!( 12345678.separate_by_policy(en_in.policy()),     "1,23,45,678" );
+        assert_eq!( (-1234567.5).separate_by_policy(en_in.policy()), "-12,34,567.5" );
+    }
+
+    #[test]
+    fn en_us() {
+        let en_us = load("en-US");
+
+        assert_eq!( en_us.groups(),                                  &[3] );
+        assert_eq!( 12345678.separate_by_policy(en_us.policy()),     "12,345,678" );
+    }
+
+    #[test]
+    fn minimum_grouping_digits() {
+        let es = load("es");
+
+        assert_eq!( 1234.separate_by_policy(es.policy()),            "1234" );
+        assert_eq!( 12345.separate_by_policy(es.policy()),           "12.345" );
+    }
+
+    #[test]
+    fn other_separators() {
+        assert_eq!( 1234567.separate_by_policy(load("fr").policy()),    "1\u{202F}234\u{202F}567" );
+        assert_eq!( 1234567.separate_by_policy(load("de-CH").policy()), "1’234’567" );
+    }
+
+    #[test]
+    fn fallback() {
+        assert_eq!( 1234567.separate_by_policy(load("xx").policy()),    "1,234,567" );
+    }
+}
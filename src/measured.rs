@@ -0,0 +1,65 @@
+/// A separated number along with its width, as returned by
+/// [`Separable::separate_measured`](trait.Separable.html#method.separate_measured).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Measured {
+    /// The separated number.
+    pub text:  String,
+    /// The number of characters in `text`, which is its width in a terminal
+    /// column when all the characters are single-width.
+    pub chars: usize,
+    /// The number of bytes in `text`.
+    pub bytes: usize,
+}
+
+impl Measured {
+    /// Measures `text`.
+    pub fn new(text: String) -> Self {
+        let chars = text.chars().count();
+        let bytes = text.len();
+        Measured { text, chars, bytes }
+    }
+}
+
+impl From<Measured> for String {
+    fn from(measured: Measured) -> String {
+        measured.text
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+
+    #[test]
+    fn ascii() {
+        let measured = (-1234567.5).separate_measured(policies::COMMA_SEPARATOR);
+
+        assert_eq!( measured.text,  "-1,234,567.5" );
+        assert_eq!( measured.chars, 12 );
+        assert_eq!( measured.bytes, 12 );
+    }
+
+    #[test]
+    fn multibyte_separator() {
+        let policy = SeparatorPolicy {
+            separator: "·",
+            .. policies::COMMA_SEPARATOR
+        };
+        let measured = 1234567890.separate_measured(policy);
+
+        assert_eq!( measured.text,  "1·234·567·890" );
+        assert_eq!( measured.chars, 13 );
+        assert_eq!( measured.bytes, 16 );
+
+        let policy = SeparatorPolicy {
+            separator:     "😃",
+            bind_with_zwj: true,
+            .. policies::COMMA_SEPARATOR
+        };
+        let measured = "x=1234".separate_measured(policy);
+
+        assert_eq!( measured.chars, 8 );
+        assert_eq!( measured.bytes, 13 );
+        assert_eq!( String::from(measured), "x=1😃\u{200D}234" );
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Separable, SeparatorPolicy};
+use super::display::{find_span, is_sign};
+use super::exponent::split_fraction;
+
+/// The pieces of a separated number, as returned by
+/// [`Separable::separate_parts`](trait.Separable.html#method.separate_parts).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeparatedParts {
+    /// The sign directly before the number, if it has one.
+    pub sign:     Option<char>,
+    /// The digits of the integer part, with separators added.
+    pub integer:  String,
+    /// The digits of the fractional part, without the decimal point, if the
+    /// number has a decimal point.
+    pub fraction: Option<String>,
+}
+
+/// Splits the first number in `s` into its sign, its integer part with
+/// separators added according to `policy`, and its fractional part.
+pub fn separate_parts(s: &str, policy: SeparatorPolicy) -> SeparatedParts {
+    let (before, number, after, count) =
+        find_span(s, |c| policy.is_digit(c), |c| policy.is_ignorable(c));
+
+    let sign = before.chars().next_back().filter(|&c| is_sign(c));
+
+    let (fraction, _) = split_fraction(after, policy.decimal_point, |c| policy.is_fraction_digit(c));
+    let fraction = match fraction.chars().next() {
+        Some(point) if count > 0 => {
+            let mut digits = String::with_capacity(fraction.len());
+            policy.push_digits(&mut digits, &fraction[point.len_utf8() ..], |_| true);
+            Some(digits)
+        }
+        _                        => None,
+    };
+
+    // The options for the number as a whole don’t apply to its integer part.
+    let integer_policy = SeparatorPolicy {
+        fraction_pad: None,
+        iso_currency: None,
+        auto_isolate: false,
+        prefer_power_of_ten: false,
+        .. policy
+    };
+
+    SeparatedParts {
+        sign,
+        integer: number.separate_by_policy(integer_policy),
+        fraction,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{CodePlacement, Separable, SeparatorPolicy, policies};
+    use super::SeparatedParts;
+
+    fn parts(sign: Option<char>, integer: &str, fraction: Option<&str>) -> SeparatedParts {
+        SeparatedParts {
+            sign,
+            integer:  integer.to_owned(),
+            fraction: fraction.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn signed_decimal() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( (-1234.5).separate_parts(policy),   parts(Some('-'), "1,234", Some("5")) );
+        assert_eq!( "+1234567".separate_parts(policy),  parts(Some('+'), "1,234,567", None) );
+        assert_eq!( 1234567.separate_parts(policy),     parts(None, "1,234,567", None) );
+        assert_eq!( "12.".separate_parts(policy),       parts(None, "12", Some("")) );
+    }
+
+    #[test]
+    fn surrounding_text() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( "x = -1234.25 m".separate_parts(policy),
+                    parts(Some('-'), "1,234", Some("25")) );
+        assert_eq!( "- 1234".separate_parts(policy),
+                    parts(None, "1,234", None) );
+        assert_eq!( "none".separate_parts(policy),
+                    parts(None, "", None) );
+    }
+
+    #[test]
+    fn other_policies() {
+        let german = SeparatorPolicy {
+            separator:     ".",
+            decimal_point: ',',
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "\u{2212}1234567,89".separate_parts(german),
+                    parts(Some('\u{2212}'), "1.234.567", Some("89")) );
+
+        let padded = SeparatorPolicy {
+            fraction_pad: Some((3, '0')),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( 1234.5.separate_parts(padded),
+                    parts(None, "1,234", Some("5")) );
+    }
+
+    #[test]
+    fn whole_number_options() {
+        let before = SeparatorPolicy {
+            iso_currency: Some(("EUR", CodePlacement::Before)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "-1234567.891".separate_parts(before),
+                    parts(Some('-'), "1,234,567", Some("891")) );
+
+        let after = SeparatorPolicy {
+            iso_currency: Some(("EUR", CodePlacement::After)),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "-1234567.891".separate_parts(after),
+                    parts(Some('-'), "1,234,567", Some("891")) );
+
+        let isolated = SeparatorPolicy {
+            auto_isolate: true,
+            .. policies::SPACE_SEPARATOR
+        };
+
+        assert_eq!( "מחיר 1234567".separate_parts(isolated),
+                    parts(None, "1 234 567", None) );
+    }
+
+    #[test]
+    fn power_of_ten() {
+        let policy = SeparatorPolicy {
+            prefer_power_of_ten: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( "1e6".separate_parts(policy),       parts(None, "1", None) );
+        assert_eq!( 1000000.separate_parts(policy),     parts(None, "1,000,000", None) );
+        assert_eq!( (-1000.5).separate_parts(policy),   parts(Some('-'), "1,000", Some("5")) );
+    }
+}
@@ -0,0 +1,90 @@
+use super::Separable;
+use super::display::{find_span, text_of};
+
+/// Adds separators to the integer part of `value`, asking `separator_at` at
+/// each place between two of its decimal digits. The place is the number of
+/// digits to its right, so the place between the hundreds and the thousands
+/// is 3. Returning `None` leaves the place without a separator.
+///
+/// This is the most general way of grouping, for when the grouping depends
+/// on the place, such as finer groups toward the ones place. Only the first
+/// run of ASCII decimal digits is separated, and the rest of the text is
+/// kept as it is.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::separate_by_value_fn;
+///
+/// let thousands = |place| if place % 3 == 0 { Some(",") } else { None };
+/// assert_eq!( separate_by_value_fn(&-1234567.5, thousands), "-1,234,567.5" );
+///
+/// let finer = |place| match place {
+///     1 | 2                 => Some(" "),
+///     _ if place % 3 == 0   => Some(","),
+///     _                     => None,
+/// };
+/// assert_eq!( separate_by_value_fn(&1234567, finer), "1,234,5 6 7" );
+/// ```
+pub fn separate_by_value_fn<'a, T, F>(value: &T, mut separator_at: F) -> String
+where T: Separable + ?Sized,
+      F: FnMut(u32) -> Option<&'a str> {
+
+    let text = text_of(value);
+    let (before, number, after, count) = find_span(&text, |c| c.is_ascii_digit(), |_| false);
+
+    let mut result = String::with_capacity(text.len() * 2);
+    result.push_str(before);
+
+    for (i, c) in number.chars().enumerate() {
+        result.push(c);
+
+        let place = count - i - 1;
+        if place > 0 {
+            if let Some(separator) = separator_at(place as u32) {
+                result.push_str(separator);
+            }
+        }
+    }
+
+    result.push_str(after);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::separate_by_value_fn;
+
+    fn even(place: u32) -> Option<&'static str> {
+        match place % 2 {
+            0 => Some("_"),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn even_places() {
+        assert_eq!( separate_by_value_fn(&1234567, even),          "1_23_45_67" );
+        assert_eq!( separate_by_value_fn(&123456, even),           "12_34_56" );
+        assert_eq!( separate_by_value_fn(&-98765.4321, even),      "-9_87_65.4321" );
+        assert_eq!( separate_by_value_fn(&7, even),                "7" );
+    }
+
+    #[test]
+    fn places_reported() {
+        let mut places = Vec::new();
+        let result = separate_by_value_fn("x=12345 m", |place| {
+            places.push(place);
+            None
+        });
+
+        assert_eq!( result, "x=12345 m" );
+        assert_eq!( places, [4, 3, 2, 1] );
+    }
+
+    #[test]
+    fn no_digits() {
+        assert_eq!( separate_by_value_fn("none", |_| Some(",")),   "none" );
+        assert_eq!( separate_by_value_fn("", |_| Some(",")),       "" );
+    }
+}
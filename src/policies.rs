@@ -1,3 +1,7 @@
+use std::fmt;
+use std::panic::RefUnwindSafe;
+
+use super::{PatternError, Separable};
 use super::digits::*;
 
 /// A policy for inserting separators into numbers.
@@ -10,9 +14,16 @@ use super::digits::*;
 ///
 ///   - What characters are considered digits (for skipping non-digits such as
 ///     a minus sign).
-#[derive(Debug, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct SeparatorPolicy<'a> {
     /// The separator to insert.
+    ///
+    /// Before version 0.1.4 this was a `char`. Code that sets it to a
+    /// literal, such as `separator: ','`, only needs double quotes instead,
+    /// as in `separator: ","`. Code that computes the character can use
+    /// [`SeparatorPolicy::separator_char`].
+    ///
+    /// [`SeparatorPolicy::separator_char`]: #method.separator_char
     pub separator: &'a str,
     /// The grouping. The numbers in this array give the size of the groups, from
     /// right to left, with the last number in the array giving the size of all
@@ -22,19 +33,591 @@ pub struct SeparatorPolicy<'a> {
     /// this array should be `&[3]`. However, to get a grouping like `1,23,45,678`,
     /// where the last group has size three and the others size two, you would use
     /// `&[3, 2]`.
+    ///
+    /// A zero in the array is an empty group, which puts another separator
+    /// next to the one before it, unless `collapse_separators` is set. A zero
+    /// at the end of the array means the remaining digits aren’t grouped.
     pub groups:    &'a [u8],
     /// The characters that are considered digits. If there are multiple groups of
     /// digits separated by non-digits, we only add separators to the first group.
     /// This means, for example, that the number `-12345.67` will only have separators
     /// inserted into the `12345` portion.
+    ///
+    /// Grouping is purely by position, so any characters will do, including
+    /// those of numeral systems that aren’t positional, such as Ethiopic,
+    /// whose `፻` means a hundred wherever it appears. Such numbers are still
+    /// grouped, predictably and without panicking, but the result may mean
+    /// little to a reader.
     pub digits:    &'a [char],
+    /// Characters that may appear between digits without ending the run of
+    /// digits. These are dropped from the output, so a number that was already
+    /// grouped with one of them, such as `"1\u{A0}234\u{A0}567"` pasted from
+    /// a document that uses no-break spaces, gets regrouped according to the
+    /// policy. A run of digits never starts or ends with an ignorable
+    /// character.
+    pub ignorable: &'a [char],
+    /// Whether to avoid leaving a single digit alone in the most significant
+    /// group. When set, such an orphaned digit is merged into the next group,
+    /// so that `1234567` becomes `1234,567` rather than `1,234,567`, while
+    /// `12345678` still becomes `12,345,678`.
+    pub avoid_orphan: bool,
+    /// Whether to render an exponent in scientific notation, such as the `e3`
+    /// in `1.5e3`, as a power of ten with superscript digits, as in
+    /// `1.5 × 10³`. An explicit `+` sign on the exponent is dropped.
+    pub superscript_exponent: bool,
+    /// Separators to use instead of `separator` for the more significant
+    /// boundaries between groups. The boundaries are numbered from the right,
+    /// starting at zero, and each entry `(n, sep)` says to use `sep` from
+    /// boundary `n` onward, until a later entry takes over. So
+    /// `&[(2, " ")]` formats `1234567890123` as `1 234 567,890,123`.
+    ///
+    /// The entries should be sorted by boundary number.
+    pub separators_after: &'a [(usize, &'a str)],
+    /// Pads the fractional part, if shorter, to the given number of digits
+    /// with the given character, so that the decimal points of numbers
+    /// formatted in a column line up. For example, with `Some((2, ' '))`,
+    /// `1234.5` becomes `"1,234.5 "`. A number with no fractional part is
+    /// padded by one more character, to make up for the decimal point.
+    ///
+    /// This pads the text rather than the value, so it doesn’t add zeros
+    /// unless asked to.
+    pub fraction_pad: Option<(usize, char)>,
+    /// The character that starts the fractional part of a number, such as
+    /// `'.'` in `1234.5` or `','` in `1234,5`. Options that work on the
+    /// fractional part use this to find it; the decimal point isn’t
+    /// translated to it when inserting separators.
+    pub decimal_point: char,
+    /// What to do with a malformed number, that is, one with a second
+    /// decimal point followed by more digits, such as `1.2.3`.
+    pub on_malformed: OnMalformed,
+    /// The digits of the fractional part, if they differ from `digits`. The
+    /// first run of `digits` is still the integer part that gets separated;
+    /// this only affects finding where the fractional part after the
+    /// decimal point ends.
+    pub fraction_digits: Option<&'a [char]>,
+    /// Whether to put a zero-width joiner (U+200D) after each separator, so
+    /// that user interfaces treat the grouped number as a single unit when
+    /// selecting text.
+    pub bind_with_zwj: bool,
+    /// Whether to remove separators already in the number before adding new
+    /// ones. When set, the characters of `separator` and of the separators in
+    /// `separators_after` are ignored within the first run of digits, however
+    /// irregularly they are placed, so `1,23,456` is regrouped as `123,456`.
+    /// When `separator` is a space of some width, the other spaces used as
+    /// digit separators (U+0020, U+00A0, U+2009, and U+202F) are removed too,
+    /// so numbers grouped with any of them are regrouped consistently.
+    pub regroup: bool,
+    /// A heavier separator to use instead of the usual one after every so
+    /// many groups, counting from the right. With a minor separator of `.`,
+    /// `Some(("′", 3))` formats `123456789012` as `123′456.789.012`.
+    ///
+    /// This takes precedence over `separators_after`.
+    pub major_separator: Option<(&'a str, usize)>,
+    /// A function giving the separator for each boundary between groups,
+    /// numbered from the right starting at zero, as for `separators_after`.
+    /// This allows arbitrary text, such as a marker for millions, at chosen
+    /// boundaries, and takes precedence over all the other separator fields.
+    ///
+    /// Since the separators it returns aren’t known ahead of time, `regroup`
+    /// doesn’t remove them.
+    pub separator_fn: Option<fn(usize) -> &'a str>,
+    /// Groups the digits from the left instead of from the right, starting
+    /// with a group of this many digits. The rest are grouped by the last
+    /// entry of `groups`, the one that repeats, with any shorter group at the
+    /// right end. So with `groups: &[4]`, `Some(1)` formats `A12345678` (as
+    /// alphanumeric digits) as `A 1234 5678`, and `Some(0)` groups by fours
+    /// from the left throughout. `avoid_orphan` has no effect when this is
+    /// set.
+    pub first_group: Option<u8>,
+    /// Whether to write a single separator where empty groups, that is,
+    /// zeros in `groups`, would put several in a row. So with
+    /// `groups: &[3, 0, 3]`, `1234567` becomes `1,234,567` instead of
+    /// `1,234,,567`. When the boundaries call for different separators, the
+    /// one for the most significant boundary is written.
+    pub collapse_separators: bool,
+    /// Cuts the fractional part, if longer, down to the given number of
+    /// digits, without rounding, so `1.999` becomes `1.9` with `Some(1)`.
+    /// With `Some(0)`, the decimal point goes too. This works on the text, so
+    /// it is exact.
+    pub truncate_fraction: Option<usize>,
+    /// A function applied to each digit of the integer and fractional parts
+    /// in the output, such as to write ASCII digits as fullwidth ones. The
+    /// input is still read using `digits`; only the output changes.
+    pub map_digits: Option<fn(char) -> char>,
+    /// Whether to add separators to the digits of an exponent in scientific
+    /// notation too, so `1.5e-1234567` becomes `1.5e-1,234,567`. This has no
+    /// effect when `superscript_exponent` is set.
+    pub group_exponent: bool,
+    /// Groups the digits outward in both directions from a point other than
+    /// the decimal point, as for barcodes and serial numbers. The pivot is
+    /// given as the number of digits before it. Digits to its left are
+    /// grouped as usual, ending at the pivot, and digits to its right are
+    /// grouped from the pivot by the last entry of `groups`, the one that
+    /// repeats, with a separator at the pivot itself. So with `groups: &[2]`,
+    /// `Some(4)` formats `123456789` as `12,34,56,78,9`. A pivot at or past
+    /// the last digit has no effect, and this takes precedence over
+    /// `first_group`.
+    pub pivot: Option<usize>,
+    /// Whether to remove trailing `0`s from the fractional part, along with
+    /// the decimal point if no digits remain, so `1234.500` becomes `1,234.5`
+    /// and `1234.000` becomes `1,234`. This happens after `truncate_fraction`
+    /// cuts the fractional part down and before `fraction_pad` pads it back
+    /// out, so with both set, a fraction is never shorter than the padding.
+    pub trim_fraction_zeros: bool,
+    /// Whether the output is headed somewhere that can only show ASCII, such
+    /// as a plain terminal. When set, `ascii_fallback` replaces any
+    /// separator that isn’t ASCII, and `bind_with_zwj` has no effect.
+    pub ascii_only: bool,
+    /// The separator to use in place of a non-ASCII one when `ascii_only` is
+    /// set. So a policy separating with narrow no-break spaces and a fallback
+    /// of `Some(" ")` writes ordinary spaces instead. With `None`, non-ASCII
+    /// separators are kept.
+    pub ascii_fallback: Option<&'a str>,
+    /// Leaves out the middle of a long number, keeping the given number of
+    /// groups at each end, with the given ellipsis in place of the groups
+    /// left out and the separators around them. So `Some((2, "…"))` formats
+    /// `123456789012345` as `123,456…012,345`, as for showing large IDs in a
+    /// narrow space. Numbers with no more than twice that many groups, and a
+    /// count of zero, are unaffected.
+    pub elide_middle: Option<(usize, &'a str)>,
+    /// A function that decides which characters are digits, in place of
+    /// `digits`, for when the set of digits is easier to describe than to
+    /// list. It is also used for the fractional part unless
+    /// `fraction_digits` is set.
+    ///
+    /// Since this is a reference to a closure, the closure can capture
+    /// configuration loaded at run time. It must be `Sync` so that policies
+    /// can be shared between threads, as by [`register_policy`], and
+    /// `RefUnwindSafe` so that they can be used inside `catch_unwind`.
+    ///
+    /// [`register_policy`]: ../fn.register_policy.html
+    pub digit_fn: Option<&'a (dyn Fn(char) -> bool + Sync + RefUnwindSafe)>,
+    /// Whether to wrap the number, from its sign through its fraction and
+    /// exponent, in a left-to-right isolate when the text around it contains
+    /// right-to-left characters, such as Hebrew or Arabic. Without the
+    /// isolate, the bidirectional algorithm can show the groups of a number
+    /// separated by spaces in the wrong order. Numbers in left-to-right text
+    /// are unaffected.
+    pub auto_isolate: bool,
+    /// Whether to write a number that is exactly a power of ten, with no
+    /// fraction or exponent, as `10^n` instead of grouping its digits. So
+    /// `1000000` becomes `10^6`, `-1000` becomes `-10^3`, and `1` becomes
+    /// `10^0`, while other numbers are grouped as usual.
+    pub prefer_power_of_ten: bool,
+    /// An ISO 4217 currency code, such as `EUR`, to write next to the
+    /// number, separated from it by a space, and where to put it. So
+    /// `Some(("EUR", CodePlacement::After))` formats `-1234.56` as
+    /// `-1,234.56 EUR`, and with `CodePlacement::Before`, as
+    /// `EUR -1,234.56`. Text without digits gets no code.
+    pub iso_currency: Option<(&'a str, CodePlacement)>,
+    /// The number of the lowest boundary between groups, counting from the
+    /// right starting at zero, that gets a separator. The boundaries below
+    /// it are left out, for dense tables that only need the larger groups
+    /// marked. So with `1`, `1234567890` becomes `1,234,567890`. The default
+    /// of zero keeps every separator.
+    pub min_separator_group: usize,
+    /// The number of digits to pad the integer part out to with leading
+    /// zeros, as in `format!("{:06}", 42)`. The zeros are ASCII `0`, whatever
+    /// the policy’s digits. Text without digits isn’t padded.
+    pub min_integer_digits: usize,
+    /// Whether the zeros added by `min_integer_digits` are grouped along with
+    /// the digits of the number. So `42` padded to six digits becomes
+    /// `000,042` when this is set, and `000042` when it isn’t, in which case
+    /// the zeros are written before the grouped number, without separators.
+    pub group_padding: bool,
+    /// The grouping of the digits after the decimal point, counted from the
+    /// point outward, with the last number repeating as in `groups`. So
+    /// `Some(&[3])` formats `1234.56789` as `1,234.567,89`. The groups are
+    /// separated by the separator of the lowest boundary of the integer
+    /// part, and a zero leaves the rest of the digits together. With `None`,
+    /// the fractional part isn’t grouped.
+    pub fractional_groups: Option<&'a [u8]>,
+    /// The fewest digits the integer part must have to be separated. Below
+    /// this, as with `2024` when it’s `5`, the digits are written without
+    /// separators, though the rest of the policy still applies. Digits after
+    /// the decimal point don’t count, and `0` separates every number.
+    pub min_digits: usize,
+}
+
+/// What to do with a malformed number, such as `1.2.3`, which has more than
+/// one decimal point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnMalformed {
+    /// Add separators to the first run of digits, ignoring the rest, as with a
+    /// well-formed number.
+    GroupFirst,
+    /// Leave the number as it is.
+    Passthrough,
+    /// Report an error from [`Separable::try_separate`], and otherwise leave
+    /// the number as it is, since [`Separable::separate_by_policy`] can’t
+    /// report errors.
+    ///
+    /// [`Separable::try_separate`]: trait.Separable.html#method.try_separate
+    /// [`Separable::separate_by_policy`]: trait.Separable.html#tymethod.separate_by_policy
+    Error,
+}
+
+/// Where to write a policy’s `iso_currency` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodePlacement {
+    /// Before the number and its sign, as in `EUR 1,234.56`.
+    Before,
+    /// After the number and its fraction, as in `1,234.56 EUR`.
+    After,
+}
+
+impl<'a> SeparatorPolicy<'a> {
+    /// Creates a policy for decimal digits whose groups grow toward the most
+    /// significant end, starting with `start` digits and adding `step` digits
+    /// to each subsequent group, for as long as the size fits in a `u8`.
+    ///
+    /// The group sizes are written to `groups`, which the policy borrows. A
+    /// `start` of zero results in no grouping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatorPolicy};
+    ///
+    /// let mut groups = Vec::new();
+    /// let policy = SeparatorPolicy::increasing(1, 1, ",", &mut groups);
+    ///
+    /// assert_eq!( 1234567890.separate_by_policy(policy), "1234,567,89,0" );
+    /// ```
+    pub fn increasing(start: u8, step: u8, separator: &'a str, groups: &'a mut Vec<u8>)
+        -> Self {
+
+        groups.clear();
+
+        let mut size = start as usize;
+
+        while 0 < size && size <= 255 {
+            groups.push(size as u8);
+
+            if step == 0 {
+                break;
+            }
+
+            size += step as usize;
+        }
+
+        let groups: &'a Vec<u8> = groups;
+
+        SeparatorPolicy {
+            separator,
+            groups,
+            .. COMMA_SEPARATOR
+        }
+    }
+
+    /// Creates a policy for decimal digits from a pattern such as
+    /// `"#,##,###"`, in which runs of `#` or `0` stand for the digits of
+    /// each group and the text between them is the separator. The leftmost
+    /// run holds the remaining digits, which aren’t grouped, unless the
+    /// pattern ends with `...`, as in `"#,###,..."`, in which case the
+    /// leftmost group listed repeats. The group sizes are stored in
+    /// `groups`, which the policy borrows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatorPolicy};
+    ///
+    /// let mut groups = Vec::new();
+    /// let policy = SeparatorPolicy::from_pattern("#,##,###,...", &mut groups).unwrap();
+    /// assert_eq!( 123456789.separate_by_policy(policy), "12,34,56,789" );
+    ///
+    /// let mut groups = Vec::new();
+    /// let policy = SeparatorPolicy::from_pattern("# ###", &mut groups).unwrap();
+    /// assert_eq!( 123456789.separate_by_policy(policy), "123456 789" );
+    /// ```
+    pub fn from_pattern(pattern: &'a str, groups: &'a mut Vec<u8>)
+                        -> Result<Self, PatternError> {
+        let is_placeholder = |c| c == '#' || c == '0';
+        let (body, repeats) = match pattern.strip_suffix("...") {
+            Some(body) => (body, true),
+            None       => (pattern, false),
+        };
+        let number   = body.trim_end_matches(|c| !is_placeholder(c));
+        let trailing = &body[number.len() ..];
+
+        if !number.starts_with(is_placeholder) || !repeats && !trailing.is_empty() {
+            return Err(PatternError::MissingDigits);
+        }
+
+        let mut separators = number.split(is_placeholder).filter(|sep| !sep.is_empty());
+        let separator = match separators.next() {
+            Some(separator) => separator,
+            None            => return Err(PatternError::NoSeparator),
+        };
+        if separators.any(|sep| sep != separator) || !trailing.is_empty() && trailing != separator {
+            return Err(PatternError::MixedSeparators);
+        }
+
+        groups.clear();
+        let mut runs = number.rsplit(|c| !is_placeholder(c)).filter(|run| !run.is_empty()).peekable();
+
+        while let Some(run) = runs.next() {
+            // The leftmost run holds the remaining digits, however many.
+            if runs.peek().is_none() {
+                break;
+            }
+            if run.len() > usize::from(u8::MAX) {
+                return Err(PatternError::GroupTooLong);
+            }
+            groups.push(run.len() as u8);
+        }
+
+        if !repeats {
+            groups.push(0);
+        }
+
+        let groups: &'a Vec<u8> = groups;
+        Ok(SeparatorPolicy {
+            separator,
+            groups,
+            .. COMMA_SEPARATOR
+        })
+    }
+
+    /// Creates a policy like [`COMMA_SEPARATOR`] but with `c` as the
+    /// separator, for code written when the separator was a `char`. The
+    /// separator is encoded into `buf`, which the policy borrows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatorPolicy};
+    ///
+    /// let mut buf = [0; 4];
+    /// let policy = SeparatorPolicy {
+    ///     groups: &[3, 2],
+    ///     .. SeparatorPolicy::separator_char('\'', &mut buf)
+    /// };
+    ///
+    /// assert_eq!( 12345678.separate_by_policy(policy), "1'23'45'678" );
+    /// ```
+    ///
+    /// [`COMMA_SEPARATOR`]: policies/constant.COMMA_SEPARATOR.html
+    pub fn separator_char(c: char, buf: &'a mut [u8; 4]) -> Self {
+        SeparatorPolicy {
+            separator: c.encode_utf8(buf),
+            .. COMMA_SEPARATOR
+        }
+    }
+
+    /// Returns a function that separates values according to this policy,
+    /// for passing to table builders and other code that takes a formatter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::policies;
+    ///
+    /// let cells: Vec<String> = [1234, 56789, 1234567].iter()
+    ///     .map(policies::COMMA_SEPARATOR.formatter())
+    ///     .collect();
+    ///
+    /// assert_eq!( cells, ["1,234", "56,789", "1,234,567"] );
+    /// ```
+    pub fn formatter<T: Separable + ?Sized>(self) -> impl Fn(&T) -> String + 'a {
+        move |value| value.separate_by_policy(self)
+    }
 }
 
+impl<'a> SeparatorPolicy<'a> {
+    // The separator to insert at the given boundary between groups, counting
+    // from the right.
+    pub(crate) fn separator_at(&self, boundary: usize) -> &'a str {
+        let separator = self.unicode_separator_at(boundary);
+
+        match self.ascii_fallback {
+            Some(fallback) if self.ascii_only && !separator.is_ascii() => fallback,
+            _ => separator,
+        }
+    }
+
+    // The separator to insert at the given boundary, before considering
+    // `ascii_only`.
+    fn unicode_separator_at(&self, boundary: usize) -> &'a str {
+        if let Some(separator_fn) = self.separator_fn {
+            return separator_fn(boundary);
+        }
+
+        if let Some((major, every)) = self.major_separator {
+            if every > 0 && boundary % every == every - 1 {
+                return major;
+            }
+        }
+
+        self.separators_after.iter()
+            .take_while(|&&(start, _)| start <= boundary)
+            .last()
+            .map_or(self.separator, |&(_, separator)| separator)
+    }
+
+    // The currency code to write next to the number, if any.
+    pub(crate) fn currency_code(&self) -> &'a str {
+        match self.iso_currency {
+            Some((code, _)) => code,
+            None            => "",
+        }
+    }
+
+    // The text to insert in place of the groups left out by `elide_middle`.
+    pub(crate) fn ellipsis(&self) -> &'a str {
+        match self.elide_middle {
+            Some((_, ellipsis)) => ellipsis,
+            None                => "",
+        }
+    }
+
+    // The text to insert after each separator.
+    pub(crate) fn joiner(&self) -> &'static str {
+        if self.bind_with_zwj && !self.ascii_only { "\u{200D}" } else { "" }
+    }
+
+    // Whether `c` is skipped over, and dropped, within the run of digits.
+    pub(crate) fn is_ignorable(&self, c: char) -> bool {
+        self.ignorable.contains(&c)
+            || self.regroup && (self.separator.contains(c)
+                                || self.joiner().contains(c)
+                                || self.separators_after.iter().any(|&(_, sep)| sep.contains(c))
+                                || self.major_separator.iter().any(|&(sep, _)| sep.contains(c))
+                                || self.ascii_only && self.ascii_fallback.iter()
+                                                          .any(|sep| sep.contains(c))
+                                || self.is_space_separated() && SPACES.contains(&c))
+    }
+
+    // Whether the policy does no more to the first run of digits than insert
+    // separators and joiners where the `SeparatorIterator` puts them, and adds
+    // no text of its own. Every fast path checks this, and those that copy the
+    // text after the number as it is also check `keeps_after`, so a new field
+    // that does anything else must make one of the two `false`.
+    pub(crate) fn only_inserts_separators(&self) -> bool {
+        self.map_digits.is_none()
+            && self.elide_middle.is_none()
+            && !self.auto_isolate
+            && !self.prefer_power_of_ten
+            && self.iso_currency.is_none()
+            && self.min_separator_group == 0
+            && self.min_integer_digits == 0
+            && self.min_digits == 0
+    }
+
+    // Whether the separator is made of spaces.
+    fn is_space_separated(&self) -> bool {
+        !self.separator.is_empty() && self.separator.chars().all(|c| SPACES.contains(&c))
+    }
+
+    // Appends `digits` to `result`, applying `map_digits` to those that are
+    // digits according to `is_digit`.
+    pub(crate) fn push_digits<F>(&self, result: &mut String, digits: &str, is_digit: F)
+    where F: Fn(char) -> bool {
+        match self.map_digits {
+            Some(map) => result.extend(digits.chars().map(|c| if is_digit(c) { map(c) } else { c })),
+            None      => result.push_str(digits),
+        }
+    }
+
+    // Whether `c` is a digit of the integer part.
+    pub(crate) fn is_digit(&self, c: char) -> bool {
+        match self.digit_fn {
+            Some(digit_fn) => digit_fn(c),
+            None           => self.digits.contains(&c),
+        }
+    }
+
+    // Whether `c` is a digit of the fractional part.
+    pub(crate) fn is_fraction_digit(&self, c: char) -> bool {
+        match self.fraction_digits {
+            Some(digits) => digits.contains(&c),
+            None         => self.is_digit(c),
+        }
+    }
+}
+
+// Written by hand because `digit_fn` is a closure, which has no `Debug`.
+impl<'a> fmt::Debug for SeparatorPolicy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SeparatorPolicy")
+         .field("separator", &self.separator)
+         .field("groups", &self.groups)
+         .field("digits", &self.digits)
+         .field("ignorable", &self.ignorable)
+         .field("avoid_orphan", &self.avoid_orphan)
+         .field("superscript_exponent", &self.superscript_exponent)
+         .field("separators_after", &self.separators_after)
+         .field("fraction_pad", &self.fraction_pad)
+         .field("decimal_point", &self.decimal_point)
+         .field("on_malformed", &self.on_malformed)
+         .field("fraction_digits", &self.fraction_digits)
+         .field("bind_with_zwj", &self.bind_with_zwj)
+         .field("regroup", &self.regroup)
+         .field("major_separator", &self.major_separator)
+         .field("separator_fn", &self.separator_fn)
+         .field("first_group", &self.first_group)
+         .field("collapse_separators", &self.collapse_separators)
+         .field("truncate_fraction", &self.truncate_fraction)
+         .field("map_digits", &self.map_digits)
+         .field("group_exponent", &self.group_exponent)
+         .field("pivot", &self.pivot)
+         .field("trim_fraction_zeros", &self.trim_fraction_zeros)
+         .field("ascii_only", &self.ascii_only)
+         .field("ascii_fallback", &self.ascii_fallback)
+         .field("elide_middle", &self.elide_middle)
+         .field("digit_fn", &self.digit_fn.map(|_| "<closure>"))
+         .field("auto_isolate", &self.auto_isolate)
+         .field("prefer_power_of_ten", &self.prefer_power_of_ten)
+         .field("iso_currency", &self.iso_currency)
+         .field("min_separator_group", &self.min_separator_group)
+         .field("min_integer_digits", &self.min_integer_digits)
+         .field("group_padding", &self.group_padding)
+         .field("fractional_groups", &self.fractional_groups)
+         .field("min_digits", &self.min_digits)
+         .finish()
+    }
+}
+
+// The spaces commonly used as digit separators.
+const SPACES: &[char] = &[' ', '\u{00A0}', '\u{2009}', '\u{202F}'];
+
 /// Policy for placing a comma every three decimal digits.
 pub const COMMA_SEPARATOR: SeparatorPolicy = SeparatorPolicy {
     separator:  ",",
     groups:     &[3],
     digits:     ASCII_DECIMAL,
+    ignorable:  &[],
+    avoid_orphan: false,
+    superscript_exponent: false,
+    separators_after: &[],
+    fraction_pad: None,
+    decimal_point: '.',
+    on_malformed: OnMalformed::GroupFirst,
+    fraction_digits: None,
+    bind_with_zwj: false,
+    regroup: false,
+    major_separator: None,
+    separator_fn: None,
+    first_group: None,
+    collapse_separators: false,
+    truncate_fraction: None,
+    map_digits: None,
+    group_exponent: false,
+    pivot: None,
+    trim_fraction_zeros: false,
+    ascii_only: false,
+    ascii_fallback: None,
+    elide_middle: None,
+    digit_fn: None,
+    auto_isolate: false,
+    prefer_power_of_ten: false,
+    iso_currency: None,
+    min_separator_group: 0,
+    min_integer_digits: 0,
+    group_padding: false,
+    fractional_groups: None,
+    min_digits: 0,
 };
 
 /// Policy for placing a space every three decimal digits.
@@ -42,6 +625,37 @@ pub const SPACE_SEPARATOR: SeparatorPolicy = SeparatorPolicy {
     separator:  " ",
     groups:     &[3],
     digits:     ASCII_DECIMAL,
+    ignorable:  &[],
+    avoid_orphan: false,
+    superscript_exponent: false,
+    separators_after: &[],
+    fraction_pad: None,
+    decimal_point: '.',
+    on_malformed: OnMalformed::GroupFirst,
+    fraction_digits: None,
+    bind_with_zwj: false,
+    regroup: false,
+    major_separator: None,
+    separator_fn: None,
+    first_group: None,
+    collapse_separators: false,
+    truncate_fraction: None,
+    map_digits: None,
+    group_exponent: false,
+    pivot: None,
+    trim_fraction_zeros: false,
+    ascii_only: false,
+    ascii_fallback: None,
+    elide_middle: None,
+    digit_fn: None,
+    auto_isolate: false,
+    prefer_power_of_ten: false,
+    iso_currency: None,
+    min_separator_group: 0,
+    min_integer_digits: 0,
+    group_padding: false,
+    fractional_groups: None,
+    min_digits: 0,
 };
 
 /// Policy for placing a period every three decimal digits.
@@ -49,6 +663,37 @@ pub const DOT_SEPARATOR: SeparatorPolicy = SeparatorPolicy {
     separator:  ".",
     groups:     &[3],
     digits:     ASCII_DECIMAL,
+    ignorable:  &[],
+    avoid_orphan: false,
+    superscript_exponent: false,
+    separators_after: &[],
+    fraction_pad: None,
+    decimal_point: '.',
+    on_malformed: OnMalformed::GroupFirst,
+    fraction_digits: None,
+    bind_with_zwj: false,
+    regroup: false,
+    major_separator: None,
+    separator_fn: None,
+    first_group: None,
+    collapse_separators: false,
+    truncate_fraction: None,
+    map_digits: None,
+    group_exponent: false,
+    pivot: None,
+    trim_fraction_zeros: false,
+    ascii_only: false,
+    ascii_fallback: None,
+    elide_middle: None,
+    digit_fn: None,
+    auto_isolate: false,
+    prefer_power_of_ten: false,
+    iso_currency: None,
+    min_separator_group: 0,
+    min_integer_digits: 0,
+    group_padding: false,
+    fractional_groups: None,
+    min_digits: 0,
 };
 
 /// Policy for placing an underscore every three decimal digits.
@@ -56,11 +701,73 @@ pub const UNDERSCORE_SEPARATOR: SeparatorPolicy = SeparatorPolicy {
     separator:  "_",
     groups:     &[3],
     digits:     ASCII_DECIMAL,
+    ignorable:  &[],
+    avoid_orphan: false,
+    superscript_exponent: false,
+    separators_after: &[],
+    fraction_pad: None,
+    decimal_point: '.',
+    on_malformed: OnMalformed::GroupFirst,
+    fraction_digits: None,
+    bind_with_zwj: false,
+    regroup: false,
+    major_separator: None,
+    separator_fn: None,
+    first_group: None,
+    collapse_separators: false,
+    truncate_fraction: None,
+    map_digits: None,
+    group_exponent: false,
+    pivot: None,
+    trim_fraction_zeros: false,
+    ascii_only: false,
+    ascii_fallback: None,
+    elide_middle: None,
+    digit_fn: None,
+    auto_isolate: false,
+    prefer_power_of_ten: false,
+    iso_currency: None,
+    min_separator_group: 0,
+    min_integer_digits: 0,
+    group_padding: false,
+    fractional_groups: None,
+    min_digits: 0,
 };
 
 /// Policy for placing a space every four hexadecimal digits.
 pub const HEX_FOUR: SeparatorPolicy = SeparatorPolicy {
     separator:  " ",
     groups:     &[4],
-    digits: ASCII_HEXADECIMAL,
+    digits:     ASCII_HEXADECIMAL,
+    ignorable:  &[],
+    avoid_orphan: false,
+    superscript_exponent: false,
+    separators_after: &[],
+    fraction_pad: None,
+    decimal_point: '.',
+    on_malformed: OnMalformed::GroupFirst,
+    fraction_digits: None,
+    bind_with_zwj: false,
+    regroup: false,
+    major_separator: None,
+    separator_fn: None,
+    first_group: None,
+    collapse_separators: false,
+    truncate_fraction: None,
+    map_digits: None,
+    group_exponent: false,
+    pivot: None,
+    trim_fraction_zeros: false,
+    ascii_only: false,
+    ascii_fallback: None,
+    elide_middle: None,
+    digit_fn: None,
+    auto_isolate: false,
+    prefer_power_of_ten: false,
+    iso_currency: None,
+    min_separator_group: 0,
+    min_integer_digits: 0,
+    group_padding: false,
+    fractional_groups: None,
+    min_digits: 0,
 };
@@ -0,0 +1,116 @@
+use std::ops::Range;
+
+use super::{GroupEvent, SeparatorPolicy};
+use super::visit::visit;
+
+/// What a range of a separated number holds, as reported by
+/// [`Separable::separate_ranges`](trait.Separable.html#method.separate_ranges).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RangeKind {
+    /// A single digit, of the integer part, fraction, or exponent.
+    Digit,
+    /// A separator between two groups, including any zero-width joiner after
+    /// it.
+    Separator,
+    /// Any other text, such as a sign, decimal point, or label.
+    Other,
+}
+
+/// Adds separators to `s` according to `policy`, returning the result along
+/// with the byte ranges of its digits, separators, and other text, in order.
+pub fn separate_ranges(s: &str, policy: SeparatorPolicy)
+                       -> (String, Vec<(Range<usize>, RangeKind)>) {
+    let mut result = String::new();
+    let mut ranges = Vec::new();
+
+    visit(s, policy, &mut |event| match event {
+        GroupEvent::Digits(digits) => {
+            for c in digits.chars() {
+                push(&mut result, &mut ranges, c.encode_utf8(&mut [0; 4]), RangeKind::Digit);
+            }
+        }
+        GroupEvent::Separator(separator) =>
+            push(&mut result, &mut ranges, separator, RangeKind::Separator),
+        GroupEvent::Before(text) | GroupEvent::After(text) => {
+            for c in text.chars() {
+                let kind = if policy.is_digit(c) || policy.is_fraction_digit(c) {
+                    RangeKind::Digit
+                } else {
+                    RangeKind::Other
+                };
+                push(&mut result, &mut ranges, c.encode_utf8(&mut [0; 4]), kind);
+            }
+        }
+    });
+
+    (result, ranges)
+}
+
+// Appends `text` to `result` and its range to `ranges`, extending the last
+// range instead if it is of the same kind and `kind` isn’t `Digit`.
+fn push(result: &mut String, ranges: &mut Vec<(Range<usize>, RangeKind)>,
+        text: &str, kind: RangeKind) {
+    let start = result.len();
+    result.push_str(text);
+
+    match ranges.last_mut() {
+        Some((range, last)) if *last == kind && kind != RangeKind::Digit =>
+            range.end = result.len(),
+        _ =>
+            ranges.push((start .. result.len(), kind)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::RangeKind::*;
+
+    #[test]
+    fn thousands() {
+        let (text, ranges) = 1234.separate_ranges(policies::COMMA_SEPARATOR);
+
+        assert_eq!( text, "1,234" );
+        assert_eq!( ranges, [(0 .. 1, Digit), (1 .. 2, Separator), (2 .. 3, Digit),
+                             (3 .. 4, Digit), (4 .. 5, Digit)] );
+    }
+
+    #[test]
+    fn surrounding_text() {
+        let (text, ranges) = "≈ -1234.5 m".separate_ranges(policies::COMMA_SEPARATOR);
+
+        assert_eq!( text, "≈ -1,234.5 m" );
+        assert_eq!( ranges, [(0 .. 5, Other), (5 .. 6, Digit), (6 .. 7, Separator),
+                             (7 .. 8, Digit), (8 .. 9, Digit), (9 .. 10, Digit),
+                             (10 .. 11, Other), (11 .. 12, Digit), (12 .. 14, Other)] );
+    }
+
+    #[test]
+    fn joined_separators() {
+        let policy = SeparatorPolicy {
+            separator:     "\u{202F}",
+            bind_with_zwj: true,
+            .. policies::COMMA_SEPARATOR
+        };
+        let (text, ranges) = 1234.separate_ranges(policy);
+
+        assert_eq!( ranges[1], (1 .. 7, Separator) );
+        assert_eq!( &text[ranges[1].0.clone()], "\u{202F}\u{200D}" );
+    }
+
+    #[test]
+    fn ranges_cover_text() {
+        for s in &["", "abc", "1234567.25e10", "x=98765 units"] {
+            let (text, ranges) = s.separate_ranges(policies::SPACE_SEPARATOR);
+            let mut end = 0;
+
+            for (range, _) in ranges {
+                assert_eq!( range.start, end );
+                end = range.end;
+            }
+
+            assert_eq!( end, text.len() );
+            assert_eq!( text, s.separate_by_policy(policies::SPACE_SEPARATOR) );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{Separable, SeparatorPolicy};
+use super::display::find_span;
+
+/// Converts a number formatted according to one policy to another policy’s
+/// format.
+///
+/// The first run of digits in `s` is read using `from`: its digits may be
+/// interrupted by the characters of `from.separator`, by zero-width joiners
+/// if `from.bind_with_zwj` is set, and by `from.ignorable` characters, all
+/// of which are removed, and it may be followed by
+/// `from.decimal_point` and a fractional part. The integer part is then
+/// regrouped according to `to`, and the fractional part, if any, is written
+/// after `to.decimal_point`. Any text before or after the number is kept.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{SeparatorPolicy, policies, reformat};
+///
+/// let german = SeparatorPolicy {
+///     separator:     ".",
+///     decimal_point: ',',
+///     .. policies::COMMA_SEPARATOR
+/// };
+///
+/// assert_eq!( reformat("1.234.567,89", german, policies::COMMA_SEPARATOR),
+///             "1,234,567.89" );
+/// ```
+pub fn reformat(s: &str, from: SeparatorPolicy, to: SeparatorPolicy) -> String {
+    let is_digit = |c| from.is_digit(c);
+    let (before, number, after, count) = find_span(
+        s, is_digit, |c| from.separator.contains(c) || from.joiner().contains(c)
+                         || from.ignorable.contains(&c));
+
+    let mut integer = String::with_capacity(count);
+    integer.extend(number.chars().filter(|&c| is_digit(c)));
+
+    let (fraction, after) = match after.chars().next() {
+        Some(c) if c == from.decimal_point && count > 0 => {
+            let after     = &after[c.len_utf8() ..];
+            let split     = after.find(|c| !from.is_fraction_digit(c)).unwrap_or(after.len());
+            let (fraction, after) = after.split_at(split);
+            (Some(fraction), after)
+        }
+        _ => (None, after),
+    };
+
+    let mut result = String::with_capacity(s.len() + count);
+
+    result.push_str(before);
+    result.push_str(&integer.separate_by_policy(to));
+    if let Some(fraction) = fraction {
+        result.push(to.decimal_point);
+        result.push_str(fraction);
+    }
+    result.push_str(after);
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::*;
+
+    const GERMAN: SeparatorPolicy<'static> = SeparatorPolicy {
+        separator:     ".",
+        decimal_point: ',',
+        .. policies::COMMA_SEPARATOR
+    };
+
+    const US: SeparatorPolicy<'static> = policies::COMMA_SEPARATOR;
+
+    #[test]
+    fn german_to_us() {
+        assert_eq!( reformat("1.234.567,89", GERMAN, US),     "1,234,567.89" );
+        assert_eq!( reformat("-1.234,5 €", GERMAN, US),       "-1,234.5 €" );
+        assert_eq!( reformat("12,5", GERMAN, US),             "12.5" );
+        assert_eq!( reformat("1234567", GERMAN, US),          "1,234,567" );
+    }
+
+    #[test]
+    fn us_to_german() {
+        assert_eq!( reformat("1,234,567.89", US, GERMAN),     "1.234.567,89" );
+        assert_eq!( reformat("$1,234.", US, GERMAN),          "$1.234," );
+    }
+
+    #[test]
+    fn round_trip() {
+        for input in &["1.234.567,89", "0,001", "987", "-12.345.678.901,2345"] {
+            let us = reformat(input, GERMAN, US);
+            assert_eq!( reformat(&us, US, GERMAN), *input );
+        }
+    }
+
+    #[test]
+    fn french_round_trip() {
+        let bare = SeparatorPolicy { separator: "", .. US };
+
+        // Locale data for French has used both the no-break space and the
+        // narrow no-break space.
+        for &separator in &["\u{A0}", "\u{202F}"] {
+            let french = SeparatorPolicy {
+                separator,
+                decimal_point: ',',
+                .. US
+            };
+
+            let formatted = 1234567.separate_by_policy(french);
+            assert_eq!( formatted, format!("1{0}234{0}567", separator) );
+            assert_eq!( reformat(&formatted, french, bare),              "1234567" );
+            assert_eq!( reformat(&format!("-{},5 €", formatted), french, bare),
+                        "-1234567.5 €" );
+            assert_eq!( reformat(&formatted, french, french),            formatted );
+        }
+    }
+
+    #[test]
+    fn irregular_input_grouping() {
+        assert_eq!( reformat("1.23.4567,8", GERMAN, US),      "1,234,567.8" );
+        assert_eq!( reformat("no number", GERMAN, US),        "no number" );
+    }
+
+    #[test]
+    fn fraction_digits() {
+        let from = SeparatorPolicy {
+            fraction_digits: Some(&['０', '１', '２', '３', '４', '５', '６', '７', '８', '９']),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( reformat("1,234.５６ kg", from, policies::SPACE_SEPARATOR),
+                    "1 234.５６ kg" );
+    }
+
+    #[test]
+    fn strips_zero_width_joiners() {
+        let bound = SeparatorPolicy {
+            bind_with_zwj: true,
+            .. US
+        };
+
+        assert_eq!( reformat("1,\u{200D}234,\u{200D}567.8", bound, GERMAN),
+                    "1.234.567,8" );
+        assert_eq!( reformat("1234567.8", US, bound),
+                    "1,\u{200D}234,\u{200D}567.8" );
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use std::ptr;
+use std::sync::{Once, PoisonError, RwLock};
+
+use super::SeparatorPolicy;
+
+type Registry = RwLock<HashMap<&'static str, SeparatorPolicy<'static>>>;
+
+// `OnceLock` would do this without `unsafe`, but it needs Rust 1.70.
+fn registry() -> &'static Registry {
+    static INIT: Once = Once::new();
+    static mut REGISTRY: *const Registry = ptr::null();
+
+    // SAFETY: This is the only write to `REGISTRY`, and `call_once` runs it
+    // at most once, so no other thread can be reading the static then:
+    // every caller waits in `call_once` until the write has finished. The
+    // assignment writes the pointer directly rather than through a `&mut`
+    // to the static, and no such reference is ever made.
+    unsafe {
+        INIT.call_once(|| REGISTRY = Box::into_raw(Box::new(RwLock::new(HashMap::new()))));
+    }
+
+    // SAFETY: `call_once` has returned, so `REGISTRY` has been initialized
+    // and won’t be written again. Reading it copies the pointer out without
+    // making any reference to the static. It points to a registry that is
+    // leaked, so the shared reference to it is valid for the rest of the
+    // program, and shared references are all that are ever made to it,
+    // since it is only mutated through its `RwLock`. Sharing it between
+    // threads is sound because `SeparatorPolicy` is `Send` and `Sync`,
+    // though the raw pointer keeps the compiler from checking that.
+    unsafe { &*REGISTRY }
+}
+
+/// Registers `policy` under `name`, so that other parts of a program, such as
+/// plugins, can look it up with [`policy_by_name`]. Returns the policy that
+/// was registered under `name` before, if any.
+///
+/// The registry is shared by all threads and safe to use from any of them,
+/// but lookups wait while a registration is in progress, so it is best to
+/// register policies at startup.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{Separable, SeparatorPolicy, policies, policy_by_name, register_policy};
+///
+/// register_policy("apostrophe", SeparatorPolicy {
+///     separator: "'",
+///     .. policies::COMMA_SEPARATOR
+/// });
+///
+/// let policy = policy_by_name("apostrophe").unwrap();
+/// assert_eq!( 1234567.separate_by_policy(policy), "1'234'567" );
+/// ```
+///
+/// [`policy_by_name`]: fn.policy_by_name.html
+pub fn register_policy(name: &'static str, policy: SeparatorPolicy<'static>)
+                       -> Option<SeparatorPolicy<'static>> {
+    registry().write()
+              .unwrap_or_else(PoisonError::into_inner)
+              .insert(name, policy)
+}
+
+/// Looks up the policy registered under `name` with [`register_policy`].
+///
+/// # Examples
+///
+/// ```
+/// use thousands::policy_by_name;
+///
+/// assert!( policy_by_name("not registered").is_none() );
+/// ```
+///
+/// [`register_policy`]: fn.register_policy.html
+pub fn policy_by_name(name: &str) -> Option<SeparatorPolicy<'static>> {
+    registry().read()
+              .unwrap_or_else(PoisonError::into_inner)
+              .get(name)
+              .copied()
+}
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::{policy_by_name, register_policy};
+
+    #[test]
+    fn register_and_look_up() {
+        let indian = SeparatorPolicy {
+            groups: &[3, 2],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert!( policy_by_name("test-indian").is_none() );
+        assert!( register_policy("test-indian", indian).is_none() );
+
+        let policy = policy_by_name("test-indian").unwrap();
+        assert_eq!( 12345678.separate_by_policy(policy), "1,23,45,678" );
+    }
+
+    #[test]
+    fn replace() {
+        register_policy("test-replaced", policies::COMMA_SEPARATOR);
+        let previous = register_policy("test-replaced", policies::SPACE_SEPARATOR).unwrap();
+
+        assert_eq!( 12345.separate_by_policy(previous), "12,345" );
+        assert_eq!( 12345.separate_by_policy(policy_by_name("test-replaced").unwrap()),
+                    "12 345" );
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        register_policy("test-threads", policies::UNDERSCORE_SEPARATOR);
+
+        let result = thread::spawn(|| policy_by_name("test-threads").map(|policy| {
+            12345.separate_by_policy(policy)
+        })).join().unwrap();
+
+        assert_eq!( result.as_deref(), Some("12_345") );
+    }
+}
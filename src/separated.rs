@@ -0,0 +1,269 @@
+use std::fmt::{self, Write};
+
+use super::{Separable, SeparatorPolicy, display, policies};
+use super::stream::{Piece, power_of_ten_text, walk, with_formatted_after};
+
+/// A value that displays with separators, as returned by
+/// [`Separable::separated`](trait.Separable.html#method.separated).
+///
+/// Nothing is formatted until the value is displayed. Then the value’s own
+/// text is formatted into a `String`, as `Separable` does for every method,
+/// and the separated result is written from it straight to the formatter.
+/// A width, fill, and alignment, as in `{:>12}`, pad the separated result as
+/// they would a `str`, and so does a precision, which truncates it; only
+/// the latter builds the result in a `String` first.
+///
+/// Its `Debug` output is the same as its `Display` output, without quotes,
+/// so that a manual `Debug` implementation can show a field with separators.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt;
+/// use thousands::{Separable, policies};
+///
+/// struct Stats { bytes: u64 }
+///
+/// impl fmt::Debug for Stats {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         f.debug_struct("Stats")
+///          .field("bytes", &self.bytes.separated(policies::COMMA_SEPARATOR))
+///          .finish()
+///     }
+/// }
+///
+/// assert_eq!( format!("{:?}", Stats { bytes: 1234567 }), "Stats { bytes: 1,234,567 }" );
+/// ```
+pub struct Separated<'a, T: ?Sized + 'a> {
+    value:  &'a T,
+    policy: SeparatorPolicy<'a>,
+}
+
+impl<'a, T: ?Sized> Separated<'a, T> {
+    /// Wraps `value` to display it separated according to `policy`.
+    pub fn new(value: &'a T, policy: SeparatorPolicy<'a>) -> Self {
+        Separated { value, policy }
+    }
+}
+
+impl<'a, T: ?Sized> Clone for Separated<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T: ?Sized> Copy for Separated<'a, T> {}
+
+impl<'a, T: Separable + ?Sized> fmt::Display for Separated<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = display::text_of(self.value);
+
+        if f.precision().is_some() {
+            let mut result = String::new();
+            write_separated(&text, &self.policy, &mut result)?;
+            return f.pad(&result);
+        }
+
+        let width = match f.width() {
+            Some(width) => width,
+            None        => return write_separated(&text, &self.policy, f),
+        };
+
+        let mut counter = CharCounter(0);
+        write_separated(&text, &self.policy, &mut counter)?;
+
+        let padding = width.saturating_sub(counter.0);
+        let (before, after) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Right)       => (padding, 0),
+            Some(fmt::Alignment::Center)      => (padding / 2, padding - padding / 2),
+        };
+        let fill = f.fill();
+
+        for _ in 0 .. before {
+            f.write_char(fill)?;
+        }
+        write_separated(&text, &self.policy, f)?;
+        for _ in 0 .. after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Writes `text` separated according to `policy` to `out`.
+fn write_separated<W: Write + ?Sized>(text: &str, policy: &SeparatorPolicy, out: &mut W)
+    -> fmt::Result {
+
+    let mut result = Ok(());
+
+    walk(text, policy, |piece| {
+        if result.is_err() {
+            return;
+        }
+
+        result = match piece {
+            Piece::Before(text)       => out.write_str(text),
+            Piece::Digits(digits)     => match policy.map_digits {
+                Some(map) => digits.chars().map(map).try_for_each(|c| out.write_char(c)),
+                None      => out.write_str(digits),
+            },
+            Piece::Boundary(boundary) => out.write_str(policy.separator_at(boundary))
+                                            .and_then(|()| out.write_str(policy.joiner())),
+            Piece::Elision            => out.write_str(policy.ellipsis()),
+            Piece::After(after)       => {
+                let mut written = Ok(());
+                with_formatted_after(after, policy, |text| written = out.write_str(text));
+                written
+            }
+            Piece::PowerOfTen(exponent) => out.write_str(&power_of_ten_text(exponent)),
+            Piece::Currency           => out.write_str(policy.currency_code()),
+            Piece::Rest(text)         => out.write_str(text),
+        };
+    });
+
+    result
+}
+
+// Counts the characters written to it, for padding to a width.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+impl<'a, T: Separable + ?Sized> fmt::Debug for Separated<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// Wraps `value` to show it with commas in `Debug` output, for fields of a
+/// manual `Debug` implementation. This is
+/// [`Separable::separated`](trait.Separable.html#method.separated) with
+/// [`COMMA_SEPARATOR`](policies/constant.COMMA_SEPARATOR.html).
+///
+/// # Examples
+///
+/// ```
+/// use thousands::debug_separated;
+///
+/// assert_eq!( format!("{:?}", [debug_separated(&1234567), debug_separated(&12)]),
+///             "[1,234,567, 12]" );
+/// ```
+pub fn debug_separated<T: Separable + ?Sized>(value: &T) -> Separated<'_, T> {
+    Separated::new(value, policies::COMMA_SEPARATOR)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt;
+
+    use super::super::{Separable, Separated, SeparatorPolicy, debug_separated, policies};
+
+    #[test]
+    fn format() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( format!("value: {}", 1234567.separated(policy)),     "value: 1,234,567" );
+        assert_eq!( format!("{}", (-1234.5).separated(policy)),          "-1,234.5" );
+        assert_eq!( format!("{}", (&"x=98765 m").separated(policy)),     "x=98,765 m" );
+        assert_eq!( format!("{}", 12.separated(policy)),                 "12" );
+    }
+
+    #[test]
+    fn matches_separate_by_policy() {
+        let policy = SeparatorPolicy {
+            groups:               &[3, 2],
+            bind_with_zwj:        true,
+            fraction_pad:         Some((3, '0')),
+            superscript_exponent: true,
+            map_digits:           Some(|c| c.to_ascii_uppercase()),
+            .. policies::COMMA_SEPARATOR
+        };
+
+        for value in &["1234567.5e12", "0", "", "abc", "1.2.3", "-98765432"] {
+            assert_eq!( value.separated(policy).to_string(), value.separate_by_policy(policy) );
+        }
+    }
+
+    #[test]
+    fn copy_and_write() {
+        use std::fmt::Write;
+
+        let separated = (-1234567.891).separated(policies::COMMA_SEPARATOR);
+        let copy      = separated;
+        let mut out   = String::new();
+
+        write!(out, "{} / {}", separated, copy).unwrap();
+        assert_eq!( out, "-1,234,567.891 / -1,234,567.891" );
+
+        // A struct holding one can derive `Clone` and `Copy`.
+        #[derive(Clone, Copy)]
+        struct Cell<'a> {
+            value: Separated<'a, u64>,
+        }
+
+        let cell   = Cell { value: 98765u64.separated(policies::SPACE_SEPARATOR) };
+        let copied = cell;
+        assert_eq!( format!("{}|{}", cell.value, copied.value), "98 765|98 765" );
+    }
+
+    #[test]
+    fn debug_in_struct() {
+        struct Transfer {
+            name:  &'static str,
+            bytes: u64,
+            rate:  f64,
+        }
+
+        impl fmt::Debug for Transfer {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct("Transfer")
+                 .field("name", &self.name)
+                 .field("bytes", &debug_separated(&self.bytes))
+                 .field("rate", &self.rate.separated(policies::SPACE_SEPARATOR))
+                 .finish()
+            }
+        }
+
+        let transfer = Transfer { name: "backup", bytes: 1234567, rate: 98765.5 };
+
+        assert_eq!( format!("{:?}", transfer),
+                    r#"Transfer { name: "backup", bytes: 1,234,567, rate: 98 765.5 }"# );
+        assert!( format!("{:#?}", transfer).contains("    bytes: 1,234,567,\n") );
+    }
+
+    #[test]
+    fn trait_object() {
+        let value: &dyn Separable = &1234567u64;
+        assert_eq!( Separated::new(value, policies::SPACE_SEPARATOR).to_string(), "1 234 567" );
+    }
+
+    #[test]
+    fn width_and_alignment() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        macro_rules! assert_pads_like_string {
+            ($($spec:expr),*) => {$(
+                assert_eq!( format!($spec, 1234.separated(policy)),
+                            format!($spec, 1234.separate_by_policy(policy)) );
+            )*}
+        }
+
+        assert_pads_like_string!("{:12}", "{:>12}", "{:<12}", "{:^12}", "{:*^12}", "{:>3}",
+                                 "{:.3}", "{:>8.4}");
+
+        assert_eq!( format!("[{:>12}]", 1234.separated(policy)),       "[       1,234]" );
+        assert_eq!( format!("[{:-<9}]", (-1234.5).separated(policy)),  "[-1,234.5-]" );
+        assert_eq!( format!("[{:^9}]", (&"١٢٣٤").separated(SeparatorPolicy {
+                        digits: &['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+                        .. policy
+                    })),
+                    "[  ١,٢٣٤  ]" );
+    }
+}
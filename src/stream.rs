@@ -0,0 +1,370 @@
+use std::ops::Range;
+
+use super::SeparatorPolicy;
+use super::display::{find_span, is_malformed, is_sign, push_after};
+use super::exponent::{Exponent, split_fraction};
+use super::helpers::SeparatorIterator;
+use super::policies::{CodePlacement, OnMalformed};
+
+/// A piece of the output of separating a number, before separators are
+/// chosen or the text after the number is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Piece<'a> {
+    /// Text before the first run of digits, or the whole input when it is
+    /// passed through unchanged.
+    Before(&'a str),
+    /// Digits, not including any ignorable characters.
+    Digits(&'a str),
+    /// The boundary between two groups with the given number, counting from
+    /// the right starting at zero.
+    Boundary(usize),
+    /// The place where groups were left out by the policy’s `elide_middle`.
+    Elision,
+    /// The text after the first run of digits, as it appears in the input.
+    After(&'a str),
+    /// A number that is exactly the given power of ten, written in place of
+    /// its digits when the policy’s `prefer_power_of_ten` is set.
+    PowerOfTen(usize),
+    /// The policy’s `iso_currency` code.
+    Currency,
+    /// Text after the number that is written as it is, when the number is
+    /// isolated from the text around it.
+    Rest(&'a str),
+}
+
+/// Reports the pieces of `s`, separated according to `policy`, to `f` in
+/// output order. Empty text isn’t reported.
+pub fn walk<'a, F>(s: &'a str, policy: &SeparatorPolicy, mut f: F)
+where F: FnMut(Piece<'a>) {
+
+    let is_digit = |c| policy.is_digit(c);
+    let (before, number, after, count) =
+        find_span(s, is_digit, |c| policy.is_ignorable(c));
+    if policy.on_malformed != OnMalformed::GroupFirst && is_malformed(after, policy) {
+        if !s.is_empty() {
+            f(Piece::Before(s));
+        }
+        return;
+    }
+
+    let isolate = policy.auto_isolate && count > 0 && (has_rtl(before) || has_rtl(after));
+    let placement = match policy.iso_currency {
+        Some((_, placement)) if count > 0 => Some(placement),
+        _                                 => None,
+    };
+    let code_before = placement == Some(CodePlacement::Before);
+    let code_after  = placement == Some(CodePlacement::After);
+
+    let (before, sign) = match before.chars().next_back() {
+        Some(c) if (isolate || code_before) && is_sign(c) =>
+            before.split_at(before.len() - c.len_utf8()),
+        _ => (before, ""),
+    };
+
+    if !before.is_empty() {
+        f(Piece::Before(before));
+    }
+    if code_before {
+        f(Piece::Currency);
+        f(Piece::Before(" "));
+    }
+    if isolate {
+        f(Piece::Before(LRI));
+    }
+    if !sign.is_empty() {
+        f(Piece::Before(sign));
+    }
+
+    let power = if policy.prefer_power_of_ten { power_of_ten(number, after, policy) } else { None };
+    match power {
+        Some(exponent) => f(Piece::PowerOfTen(exponent)),
+        None           => walk_digits(number, count, policy, &mut f),
+    }
+
+    let (after, rest) = if power.is_some() {
+        ("", after)
+    } else if isolate || code_after {
+        split_number(after, policy)
+    } else {
+        (after, "")
+    };
+
+    if count > 0 && power.is_none() && (!after.is_empty() || policy.fraction_pad.is_some()) {
+        f(Piece::After(after));
+    }
+    if isolate {
+        f(Piece::Rest(PDI));
+    }
+    if code_after {
+        f(Piece::Rest(" "));
+        f(Piece::Currency);
+    }
+    if !rest.is_empty() {
+        f(Piece::Rest(rest));
+    }
+}
+
+// Reports the digits of `number`, which has `count` of them, and the
+// boundaries between their groups, after any zeros that pad it out to the
+// policy’s `min_integer_digits`.
+fn walk_digits<'a, F>(number: &'a str, count: usize, policy: &SeparatorPolicy, f: &mut F)
+where F: FnMut(Piece<'a>) {
+
+    let padding = if count > 0 { policy.min_integer_digits.saturating_sub(count) } else { 0 };
+    let grouped = if policy.group_padding { padding } else { 0 };
+
+    for chunk in zeros(padding - grouped) {
+        f(Piece::Digits(chunk));
+    }
+
+    let iter     = SeparatorIterator::new(policy, count + grouped);
+    let boundary = iter.sep_len();
+    let elided   = match policy.elide_middle {
+        Some((keep, _)) if keep > 0 && boundary + 1 > keep.saturating_mul(2) =>
+            keep .. boundary + 1 - keep,
+        _ => 0 .. 0,
+    };
+    let separate = count >= policy.min_digits;
+    let mut run  = Run { iter, boundary, elided, separate, policy };
+
+    for chunk in zeros(grouped) {
+        run.walk(chunk, |_| true, f);
+    }
+    run.walk(number, |c| policy.is_digit(c), f);
+}
+
+// The state of grouping the digits of a number, which may come from more
+// than one piece of text.
+struct Run<'p, 'q: 'p> {
+    iter:     SeparatorIterator<'p>,
+    // The number of the next boundary, counting from the right.
+    boundary: usize,
+    // The groups left out, numbered from the right like the boundaries.
+    elided:   Range<usize>,
+    // Whether the number has enough digits to be separated at all.
+    separate: bool,
+    policy:   &'p SeparatorPolicy<'q>,
+}
+
+impl<'p, 'q> Run<'p, 'q> {
+    // Reports the digits of `text`, which are those matching `is_digit`, and
+    // the boundaries that follow them.
+    fn walk<'a, D, F>(&mut self, text: &'a str, is_digit: D, f: &mut F)
+    where D: Fn(char) -> bool,
+          F: FnMut(Piece<'a>) {
+
+        let mut start = None;
+
+        for (i, c) in text.char_indices() {
+            if !is_digit(c) {
+                if let Some(start) = start.take() {
+                    f(Piece::Digits(&text[start .. i]));
+                }
+                continue;
+            }
+
+            if !self.elided.contains(&self.boundary) {
+                start.get_or_insert(i);
+            }
+
+            if self.iter.next() == Some(true) {
+                if let Some(start) = start.take() {
+                    f(Piece::Digits(&text[start .. i + c.len_utf8()]));
+                }
+                for k in 1 ..= self.iter.separators_here(self.policy.collapse_separators) {
+                    let right = self.boundary - k;
+                    match (self.elided.contains(&(right + 1)), self.elided.contains(&right)) {
+                        _ if !self.separate => (),
+                        _ if right < self.policy.min_separator_group => (),
+                        (false, false) => f(Piece::Boundary(right)),
+                        (false, true)  => f(Piece::Elision),
+                        _              => (),
+                    }
+                }
+                self.boundary -= self.iter.separators_here(false);
+            }
+        }
+
+        if let Some(start) = start {
+            f(Piece::Digits(&text[start ..]));
+        }
+    }
+}
+
+// Splits `count` zeros into pieces of static text.
+fn zeros(count: usize) -> impl Iterator<Item = &'static str> {
+    const ZEROS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    (0 .. count).step_by(ZEROS.len())
+                .map(move |start| &ZEROS[.. (count - start).min(ZEROS.len())])
+}
+
+// The exponent of the number made of `number` and `after`, if it is exactly
+// a power of ten with no fraction or exponent.
+fn power_of_ten(number: &str, after: &str, policy: &SeparatorPolicy) -> Option<usize> {
+    if !split_number(after, policy).0.is_empty() {
+        return None;
+    }
+
+    let mut digits = number.chars().filter(|&c| policy.is_digit(c));
+    if digits.next() != Some('1') {
+        return None;
+    }
+
+    let mut exponent = 0;
+    for c in digits {
+        if c != '0' {
+            return None;
+        }
+        exponent += 1;
+    }
+
+    Some(exponent)
+}
+
+// Left-to-right isolate and pop directional isolate, which keep a number’s
+// groups in order when the text around it is right-to-left.
+const LRI: &str = "\u{2066}";
+const PDI: &str = "\u{2069}";
+
+// Splits the text after the integer digits into the rest of the number,
+// meaning its fraction and exponent, and the text that follows it.
+fn split_number<'a>(after: &'a str, policy: &SeparatorPolicy) -> (&'a str, &'a str) {
+    let (_, rest) = split_fraction(after, policy.decimal_point, |c| policy.is_fraction_digit(c));
+    let rest = match Exponent::split(rest) {
+        Some(exponent) => exponent.rest,
+        None           => rest,
+    };
+    after.split_at(after.len() - rest.len())
+}
+
+// Whether `s` has any characters from right-to-left scripts, such as Hebrew
+// or Arabic, or right-to-left marks.
+fn has_rtl(s: &str) -> bool {
+    s.chars().any(|c| matches!(c as u32,
+        0x0590 ..= 0x08FF | 0xFB1D ..= 0xFDFF | 0xFE70 ..= 0xFEFF | 0x10800 ..= 0x10FFF
+            | 0x1E800 ..= 0x1EFFF | 0x200F | 0x202B | 0x202E | 0x2067))
+}
+
+/// Whether `policy` leaves the text after the number as it is.
+pub fn keeps_after(policy: &SeparatorPolicy) -> bool {
+    policy.fraction_pad.is_none() && policy.truncate_fraction.is_none()
+        && policy.map_digits.is_none() && !policy.superscript_exponent
+        && !policy.group_exponent && !policy.trim_fraction_zeros
+        && policy.fractional_groups.is_none()
+}
+
+/// Calls `f` with the text after the number, formatted according to
+/// `policy`, allocating only if the formatting changes it.
+pub fn with_formatted_after<F>(after: &str, policy: &SeparatorPolicy, f: F)
+where F: FnOnce(&str) {
+
+    if keeps_after(policy) {
+        f(after);
+    } else {
+        let mut formatted = String::new();
+        push_after(&mut formatted, after, policy);
+        f(&formatted);
+    }
+}
+
+/// The text for a number that is the given power of ten, such as `10^6`.
+pub fn power_of_ten_text(exponent: usize) -> String {
+    format!("10^{}", exponent)
+}
+
+/// Separates `s` according to `policy`, passing each character of the
+/// result other than the separators to `write_char`, and the number of each
+/// boundary between groups to `write_sep`.
+pub fn separate_stream<C, S>(s: &str, policy: SeparatorPolicy, mut write_char: C, mut write_sep: S)
+where C: FnMut(char),
+      S: FnMut(usize) {
+
+    walk(s, &policy, |piece| match piece {
+        Piece::Before(text)       => text.chars().for_each(&mut write_char),
+        Piece::Digits(digits)     => match policy.map_digits {
+            Some(map) => digits.chars().map(map).for_each(&mut write_char),
+            None      => digits.chars().for_each(&mut write_char),
+        },
+        Piece::Boundary(boundary) => write_sep(boundary),
+        Piece::Elision            => policy.ellipsis().chars().for_each(&mut write_char),
+        Piece::After(after) =>
+            with_formatted_after(after, &policy, |text| text.chars().for_each(&mut write_char)),
+        Piece::PowerOfTen(exponent) =>
+            power_of_ten_text(exponent).chars().for_each(&mut write_char),
+        Piece::Currency           => policy.currency_code().chars().for_each(&mut write_char),
+        Piece::Rest(text)         => text.chars().for_each(&mut write_char),
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use super::super::{Separable, SeparatorPolicy, policies};
+
+    // Separates `value`, writing the separator chosen by `sep` at each
+    // boundary.
+    fn stream<S: Separable, F: Fn(usize) -> String>(value: S, policy: SeparatorPolicy,
+                                                     sep: F) -> String {
+        let result = RefCell::new(String::new());
+        value.separate_stream(policy,
+                              |c| result.borrow_mut().push(c),
+                              |boundary| result.borrow_mut().push_str(&sep(boundary)));
+        result.into_inner()
+    }
+
+    #[test]
+    fn matches_separate_by_policy() {
+        let policies = [
+            policies::COMMA_SEPARATOR,
+            SeparatorPolicy {
+                separators_after:     &[(1, " ")],
+                fraction_pad:         Some((3, '0')),
+                superscript_exponent: true,
+                .. policies::COMMA_SEPARATOR
+            },
+            SeparatorPolicy {
+                ignorable: &['_'],
+                groups:    &[3, 2],
+                .. policies::COMMA_SEPARATOR
+            },
+        ];
+
+        for policy in &policies {
+            for input in &["-1234567.5e3 m", "x=12_345_678", "no digits", "", "12."] {
+                assert_eq!( stream(input, *policy, |boundary| policy.separator_at(boundary).to_owned()),
+                            input.separate_by_policy(*policy) );
+            }
+        }
+    }
+
+    #[test]
+    fn scale_words() {
+        let words = ["k", "M", "G"];
+
+        assert_eq!( stream(1234567890, policies::COMMA_SEPARATOR,
+                           |boundary| format!(" {} ", words[boundary])),
+                    "1 G 234 M 567 k 890" );
+    }
+
+    #[test]
+    fn major_and_minor() {
+        assert_eq!( stream(1234567890123u64, policies::COMMA_SEPARATOR,
+                           |boundary| if boundary % 2 == 1 { "  " } else { "." }.to_owned()),
+                    "1  234.567  890.123" );
+    }
+
+    #[test]
+    fn boundary_numbers() {
+        let boundaries = RefCell::new(Vec::new());
+        let chars      = RefCell::new(String::new());
+
+        (-1234567.8).separate_stream(policies::COMMA_SEPARATOR,
+                                     |c| chars.borrow_mut().push(c),
+                                     |boundary| boundaries.borrow_mut().push(boundary));
+
+        assert_eq!( boundaries.into_inner(), [1, 0] );
+        assert_eq!( chars.into_inner(), "-1234567.8" );
+    }
+}
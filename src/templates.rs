@@ -0,0 +1,190 @@
+use std::fmt::Write;
+
+use super::{Separable, Separated, SeparatorPolicy};
+
+/// Lays out the characters of `digits` according to `template`, in which
+/// each `#` is replaced by the next character of `digits` and every other
+/// character is copied literally.
+///
+/// If `digits` runs out, the output stops after the last `#` that was
+/// filled, leaving out the literal text between it and the first `#` that
+/// can’t be filled. If `digits` has characters left over once the template
+/// is used up, they are appended to the end.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::format_template;
+///
+/// assert_eq!( format_template("1234567890", "(###) ###-####"), "(123) 456-7890" );
+/// assert_eq!( format_template("12345",      "(###) ###-####"), "(123) 45" );
+/// assert_eq!( format_template("123456789012", "(###) ###-####"), "(123) 456-789012" );
+/// ```
+pub fn format_template(digits: &str, template: &str) -> String {
+    let mut digits = digits.chars();
+    let mut result = String::with_capacity(template.len() + digits.as_str().len());
+    let mut pending_literal = 0;
+
+    for (i, c) in template.char_indices() {
+        if c != '#' {
+            continue;
+        }
+
+        match digits.next() {
+            Some(digit) => {
+                result.push_str(&template[pending_literal .. i]);
+                result.push(digit);
+                pending_literal = i + 1;
+            }
+            None => return result,
+        }
+    }
+
+    result.push_str(&template[pending_literal ..]);
+    result.push_str(digits.as_str());
+
+    result
+}
+
+/// Replaces the first `{}` in `template` with `value`, separated according to
+/// `policy`.
+///
+/// This is like `format!` with a single argument, except that the template
+/// is only known at run time. Any other braces are copied literally, and a
+/// template without `{}` is returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{format_with_separators, policies};
+///
+/// assert_eq!( format_with_separators("Total: {} USD", &1234567, policies::COMMA_SEPARATOR),
+///             "Total: 1,234,567 USD" );
+/// ```
+pub fn format_with_separators<T>(template: &str, value: &T, policy: SeparatorPolicy) -> String
+where T: Separable + ?Sized {
+
+    let (before, after) = match template.find("{}") {
+        Some(index) => (&template[.. index], &template[index + 2 ..]),
+        None        => return template.to_owned(),
+    };
+
+    let mut result = String::with_capacity(template.len() + 16);
+    result.push_str(before);
+    // Writing to a `String` can’t fail.
+    let _ = write!(result, "{}", Separated::new(value, policy));
+    result.push_str(after);
+
+    result
+}
+
+/// Lays out `key` and `value`, separated according to `policy`, as a line
+/// of `total_width` characters, with the value right-aligned and dot leaders
+/// between them, as in financial statements.
+///
+/// There is always a space after the key and before the value. When the
+/// two don’t leave room for any dots, they are joined by spaces instead,
+/// enough to fill out the width and at least one, so that neither is cut
+/// short.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::{format_kv, policies};
+///
+/// assert_eq!( format_kv("Revenue", &1234567, 27, policies::COMMA_SEPARATOR),
+///             "Revenue ......... 1,234,567" );
+/// assert_eq!( format_kv("Costs", &-98765, 27, policies::COMMA_SEPARATOR),
+///             "Costs ............. -98,765" );
+/// ```
+pub fn format_kv<T>(key: &str, value: &T, total_width: usize, policy: SeparatorPolicy) -> String
+where T: Separable + ?Sized {
+
+    let value  = value.separate_by_policy(policy);
+    let filled = key.chars().count() + value.chars().count();
+    let dots   = total_width.saturating_sub(filled + 2);
+
+    let mut result = String::with_capacity(key.len() + dots + value.len() + 2);
+    result.push_str(key);
+    if dots > 0 {
+        result.push(' ');
+        result.extend((0 .. dots).map(|_| '.'));
+        result.push(' ');
+    } else {
+        let spaces = total_width.saturating_sub(filled).max(1);
+        result.extend((0 .. spaces).map(|_| ' '));
+    }
+    result.push_str(&value);
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use super::super::policies;
+
+    const US_PHONE: &str = "(###) ###-####";
+
+    #[test]
+    fn us_phone() {
+        assert_eq!( format_template("1234567890", US_PHONE), "(123) 456-7890" );
+    }
+
+    #[test]
+    fn too_few_digits() {
+        assert_eq!( format_template("123456", US_PHONE), "(123) 456" );
+        assert_eq!( format_template("1234",   US_PHONE), "(123) 4" );
+        assert_eq!( format_template("",       US_PHONE), "" );
+    }
+
+    #[test]
+    fn too_many_digits() {
+        assert_eq!( format_template("123456789012", US_PHONE), "(123) 456-789012" );
+    }
+
+    #[test]
+    fn separators_in_template() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( format_with_separators("Total: {} USD", &1234567, policy),
+                    "Total: 1,234,567 USD" );
+        assert_eq!( format_with_separators("{}", &-98765.5, policy),        "-98,765.5" );
+        assert_eq!( format_with_separators("{} of {}", &12345, policy),     "12,345 of {}" );
+        assert_eq!( format_with_separators("{{}}", &12345, policy),         "{12,345}" );
+        assert_eq!( format_with_separators("no value", &12345, policy),     "no value" );
+        assert_eq!( format_with_separators("n = {}", "x1234", policy),      "n = x1,234" );
+    }
+
+    #[test]
+    fn key_value_lines() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( format_kv("Revenue", &1234567, 27, policy),      "Revenue ......... 1,234,567" );
+        assert_eq!( format_kv("Tax", &12, 27, policy),               "Tax .................... 12" );
+        assert_eq!( format_kv("Net income", &1234567, 27, policy),   "Net income ...... 1,234,567" );
+        assert_eq!( format_kv("", &1234567, 12, policy),             " . 1,234,567" );
+        assert_eq!( format_kv("Größe", &1234.5, 15, policy),         "Größe . 1,234.5" );
+
+        for &(key, width) in &[("A", 20), ("Operating costs", 30), ("Δ", 15)] {
+            assert_eq!( format_kv(key, &-1234567, width, policy).chars().count(), width );
+        }
+    }
+
+    #[test]
+    fn key_value_nearly_full() {
+        let policy = policies::COMMA_SEPARATOR;
+
+        assert_eq!( format_kv("Revenue", &1234567, 19, policy),      "Revenue . 1,234,567" );
+        assert_eq!( format_kv("Revenue", &1234567, 18, policy),      "Revenue  1,234,567" );
+        assert_eq!( format_kv("Revenue", &1234567, 17, policy),      "Revenue 1,234,567" );
+        assert_eq!( format_kv("Revenue", &1234567, 0, policy),       "Revenue 1,234,567" );
+    }
+
+    #[test]
+    fn literal_only_template() {
+        assert_eq!( format_template("12", "ext. "), "ext. 12" );
+        assert_eq!( format_template("123", "(###)"), "(123)" );
+        assert_eq!( format_template("١٢٣", "#-#-#"), "١-٢-٣" );
+    }
+}
@@ -1,6 +1,51 @@
-use super::{SeparatorPolicy, policies};
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::{GroupEvent, Measured, RangeKind, SeparateError, Separated, SeparatedParts,
+            SeparatorPolicy, Sign, UnexpectedChar, delta, display, parts, policies, ranges,
+            stream, visit};
 
 /// Provides methods for formatting numbers with separators between the digits.
+///
+/// The trait is implemented for every type that implements `Display`, by
+/// formatting the value and then separating the text. Only the first run of
+/// digits in that text is grouped; any text before it (such as `x=`) and
+/// after it (such as ` units`) is kept unchanged, so custom numeric types
+/// whose output labels the number still separate as expected.
+///
+/// The trait is object safe, so values of different types can be formatted
+/// through `dyn Separable`. Only the generic [`separate_into`],
+/// [`separate_visit`], and [`separate_stream`] methods and [`separated`] are
+/// unavailable on trait objects; use [`Separated::new`] in place of the last.
+///
+/// # Examples
+///
+/// ```
+/// use thousands::Separable;
+///
+/// let values: Vec<Box<dyn Separable>> = vec![
+///     Box::new(1234567u32),
+///     Box::new(-98765i64),
+///     Box::new(1234.5f64),
+///     Box::new("54321"),
+/// ];
+///
+/// let formatted: Vec<String> = values.iter()
+///     .map(|value| value.separate_with_commas())
+///     .collect();
+///
+/// assert_eq!( formatted, ["1,234,567", "-98,765", "1,234.5", "54,321"] );
+/// ```
+///
+/// [`separate_into`]: #method.separate_into
+/// [`separate_visit`]: #method.separate_visit
+/// [`separate_stream`]: #method.separate_stream
+/// [`separated`]: #method.separated
+/// [`Separated::new`]: struct.Separated.html#method.new
 pub trait Separable {
     /// Inserts a comma every three digits from the right.
     ///
@@ -63,12 +108,13 @@ pub trait Separable {
     /// # Examples
     ///
     /// ```
-    /// use thousands::{Separable, SeparatorPolicy, digits};
+    /// use thousands::{Separable, SeparatorPolicy, digits, policies};
     ///
     /// let policy = SeparatorPolicy {
     ///     separator:  ":",
     ///     groups:     &[1, 2, 3, 4],
     ///     digits:     digits::ASCII_DECIMAL,
+    ///     .. policies::COMMA_SEPARATOR
     /// };
     ///
     /// assert_eq!( 1234567654321u64.separate_by_policy(policy),
@@ -77,5 +123,724 @@ pub trait Separable {
     ///
     /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
     fn separate_by_policy(&self, policy: SeparatorPolicy) -> String;
-}
 
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// a string with room for at least `capacity` bytes.
+    ///
+    /// When formatting many values into strings that will be added to
+    /// later, passing the largest expected width up front avoids growing
+    /// each string again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let result = 12345.separate_by_policy_with_capacity(policies::COMMA_SEPARATOR, 32);
+    /// assert_eq!( result, "12,345" );
+    /// assert!( result.capacity() >= 32 );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_by_policy_with_capacity(&self, policy: SeparatorPolicy,
+                                        capacity: usize) -> String {
+        display::separate_with_capacity(&display::text_of(self), policy, capacity)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], dropping
+    /// everything after the first run of digits, such as a fractional part.
+    ///
+    /// This truncates the text rather than rounding the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( 1234.567.separate_integer_only(policies::COMMA_SEPARATOR), "1,234" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_integer_only(&self, policy: SeparatorPolicy) -> String {
+        display::separate_integer_only(&display::text_of(self), policy)
+    }
+
+    /// Adds separators, choosing the digits and grouping according to a
+    /// radix prefix.
+    ///
+    /// A `0x` prefix selects hexadecimal digits in groups of four, `0b` binary
+    /// digits in groups of four, and `0o` octal digits in groups of three;
+    /// without a prefix, decimal digits are grouped by three. The prefix and
+    /// any sign before it are preserved, and the separator and remaining
+    /// options come from `policy`. This suits the alternate forms that
+    /// `format!` writes for `{:#x}`, `{:#b}`, and `{:#o}`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let policy = policies::UNDERSCORE_SEPARATOR;
+    ///
+    /// assert_eq!( "0xdeadbeef".separate_auto(policy), "0xdead_beef" );
+    /// assert_eq!( "0b10101100".separate_auto(policy), "0b1010_1100" );
+    /// assert_eq!( "12345".separate_auto(policy),      "12_345" );
+    ///
+    /// assert_eq!( format!("{:#x}", 0x1234abcd).separate_auto(policies::SPACE_SEPARATOR),
+    ///             "0x1234 abcd" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_auto(&self, policy: SeparatorPolicy) -> String {
+        display::separate_auto(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], or
+    /// returns an error if the first run of digits is interrupted by a
+    /// character that looks like it was meant to be a digit.
+    ///
+    /// Ordinarily, any character not among the policy’s digits just ends the
+    /// run, so a typo like the letter `O` in `123O456` goes unnoticed. This
+    /// looks at the letters and other alphanumeric characters right after
+    /// the digits, and if more digits follow among them, as in `123OO456`,
+    /// reports the first that isn’t a digit. An exponent such as `e56` is
+    /// allowed. The error gives the character and its byte index in the
+    /// formatted value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, UnexpectedChar, policies};
+    ///
+    /// assert_eq!( "1234567".separate_strict(policies::COMMA_SEPARATOR),
+    ///             Ok("1,234,567".to_owned()) );
+    /// assert_eq!( "123O456".separate_strict(policies::COMMA_SEPARATOR),
+    ///             Err(UnexpectedChar { ch: 'O', index: 3 }) );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_strict(&self, policy: SeparatorPolicy) -> Result<String, UnexpectedChar> {
+        display::separate_strict(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], or
+    /// returns an error if there are no digits to add them to.
+    ///
+    /// Unlike [`separate_by_policy`], which passes digitless input through
+    /// unchanged, this distinguishes input that was grouped from input that
+    /// had nothing to group. It also reports malformed numbers when the
+    /// policy’s [`on_malformed`] is `OnMalformed::Error`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{SeparateError, Separable, policies};
+    ///
+    /// assert_eq!( "12345".try_separate(policies::COMMA_SEPARATOR),
+    ///             Ok("12,345".to_owned()) );
+    /// assert_eq!( "abc".try_separate(policies::COMMA_SEPARATOR),
+    ///             Err(SeparateError::NoDigits) );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    /// [`separate_by_policy`]: #tymethod.separate_by_policy
+    /// [`on_malformed`]: struct.SeparatorPolicy.html#structfield.on_malformed
+    fn try_separate(&self, policy: SeparatorPolicy) -> Result<String, SeparateError> {
+        display::try_separate(&display::text_of(self), policy)
+    }
+
+    /// Inserts `separator` at explicitly chosen positions, rather than
+    /// according to a grouping.
+    ///
+    /// The `mask` has one entry per decimal digit, counting from the right,
+    /// and each `true` entry inserts the separator after its digit. The first
+    /// entry, for the last digit, is ignored, since nothing follows it to
+    /// separate. Entries beyond the number of digits are ignored too, and
+    /// missing entries are treated as `false`. As with the policy-based
+    /// methods, only the first run of digits gets separators, and only ASCII
+    /// digits count, so other digit sets are left as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use thousands::*;
+    /// let mask = [false, false, false, true, false, true];
+    ///
+    /// assert_eq!( 1234567.separate_with_mask("-", &mask), "12-34-567" );
+    /// ```
+    fn separate_with_mask(&self, separator: &str, mask: &[bool]) -> String {
+        display::separate_with_mask(&display::text_of(self), separator, mask)
+    }
+
+    /// Writes the number with a scale word, such as `thousand` or `million`,
+    /// after each group of three digits instead of a separator.
+    ///
+    /// `words[0]` follows the group of thousands, `words[1]` the group of
+    /// millions (or whatever scale comes next), and so on. Groups that are
+    /// all zeros are left out along with their words, and leading zeros are
+    /// dropped from the rest. Digits above the last scale word stay together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::Separable;
+    ///
+    /// let words = &["thousand", "million"];
+    ///
+    /// assert_eq!( 1234567.separate_scale_words(words),   "1 million 234 thousand 567" );
+    /// assert_eq!( 5000042.separate_scale_words(words),   "5 million 42" );
+    /// assert_eq!( 1234567.separate_scale_words(&["k"]),  "1234 k 567" );
+    /// ```
+    fn separate_scale_words(&self, words: &[&str]) -> String {
+        display::scale_words(&display::text_of(self), words)
+    }
+
+    /// Formats a whole number of minor currency units, such as cents, as a
+    /// currency amount.
+    ///
+    /// The policy’s decimal point is placed `scale` digits from the right
+    /// (padding with zeros when there are fewer digits than that), the integer
+    /// part is grouped according to `policy`, and the amount is wrapped in
+    /// `symbol_prefix` and `symbol_suffix`. A minus sign stays in front of the
+    /// prefix. Text without digits is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 123456.separate_money(2, policy, "$", ""),     "$1,234.56" );
+    /// assert_eq!( (-5).separate_money(2, policy, "$", ""),       "-$0.05" );
+    /// assert_eq!( 123456.separate_money(2, policy, "", " EUR"),  "1,234.56 EUR" );
+    /// ```
+    fn separate_money(&self, scale: usize, policy: SeparatorPolicy,
+                      symbol_prefix: &str, symbol_suffix: &str) -> String {
+        display::separate_money(&display::text_of(self), scale, policy,
+                                symbol_prefix, symbol_suffix)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], then puts
+    /// `symbol` at the far left and pads with spaces between the symbol and
+    /// the number to make the result `width` characters wide.
+    ///
+    /// This lines up amounts in a monospace column while keeping their
+    /// currency symbols lined up too. A minus sign stays with the number,
+    /// inside the padding. The result is never truncated, so it is wider than
+    /// `width` when the symbol and number don’t fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( "1234.00".separate_currency_aligned("$", 12, policy),  "$   1,234.00" );
+    /// assert_eq!( "-5.00".separate_currency_aligned("$", 12, policy),    "$      -5.00" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_currency_aligned(&self, symbol: &str, width: usize,
+                                 policy: SeparatorPolicy) -> String {
+        display::currency_aligned(symbol, width, &self.separate_by_policy(policy))
+    }
+
+    /// Adds separators to the integer part of the mantissa of a number in
+    /// scientific notation, such as `12345.6e7`, leaving text whose first
+    /// number isn’t in scientific notation unchanged.
+    ///
+    /// Numbers formatted with `{:e}` have a single-digit integer part, so they
+    /// come out unchanged, as they do from [`separate_by_policy`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( "12345.6e7".separate_mantissa(policy),  "12,345.6e7" );
+    /// assert_eq!( "12345.6".separate_mantissa(policy),    "12345.6" );
+    /// assert_eq!( format!("{:e}", 1234567.0).separate_mantissa(policy),
+    ///             "1.234567e6" );
+    /// ```
+    ///
+    /// [`separate_by_policy`]: #tymethod.separate_by_policy
+    fn separate_mantissa(&self, policy: SeparatorPolicy) -> String {
+        display::separate_mantissa(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], then
+    /// converts the whole result, including any non-digit text, to uppercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( "No. DeadBeef".separate_upper(policies::HEX_FOUR),
+    ///             "NO. DEAD BEEF" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_upper(&self, policy: SeparatorPolicy) -> String {
+        self.separate_by_policy(policy).to_uppercase()
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], then
+    /// converts the whole result, including any non-digit text, to lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( "NO. DeadBeef".separate_lower(policies::HEX_FOUR),
+    ///             "no. dead beef" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_lower(&self, policy: SeparatorPolicy) -> String {
+        self.separate_by_policy(policy).to_lowercase()
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], writing
+    /// the result to `out`.
+    ///
+    /// This works with any [`fmt::Write`] implementation, including
+    /// fixed-capacity strings such as `heapless::String<N>` or
+    /// `arrayvec::ArrayString<N>`, which report running out of capacity as an
+    /// error. The output may be partially written when an error occurs.
+    ///
+    /// The separated text is written to `out` a piece at a time, as by
+    /// [`separated`], so no `String` of the result is built. The value’s own
+    /// text is still formatted into a `String` first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let mut buffer = String::from("total: ");
+    /// 1234567.separate_into(policies::COMMA_SEPARATOR, &mut buffer).unwrap();
+    /// assert_eq!( buffer, "total: 1,234,567" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    /// [`fmt::Write`]: https://doc.rust-lang.org/std/fmt/trait.Write.html
+    /// [`separated`]: #method.separated
+    fn separate_into<W: fmt::Write>(&self, policy: SeparatorPolicy, out: &mut W) -> fmt::Result
+    where Self: Sized {
+        write!(out, "{}", self.separated(policy))
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result in a `heapless::String` with room for `N` bytes, or
+    /// `Err(())` if it doesn’t fit.
+    ///
+    /// This needs the `heapless` feature. The result is written as by
+    /// [`separate_into`], so it never goes through a `String` of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "heapless")] {
+    /// use thousands::{Separable, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 1234567.separate_heapless::<16>(policy).unwrap(), "1,234,567" );
+    /// assert!( 1234567.separate_heapless::<8>(policy).is_err() );
+    /// # }
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    /// [`separate_into`]: #method.separate_into
+    #[cfg(feature = "heapless")]
+    #[allow(clippy::result_unit_err)]
+    fn separate_heapless<const N: usize>(&self, policy: SeparatorPolicy)
+        -> Result<::heapless::String<N>, ()>
+    where Self: Sized {
+        let mut result = ::heapless::String::new();
+        self.separate_into(policy, &mut result).map_err(|_| ())?;
+        Ok(result)
+    }
+
+    /// Returns a value that displays as `self` with separators added according
+    /// to the given [`SeparatorPolicy`].
+    ///
+    /// Nothing is formatted until the result is displayed, so this suits
+    /// logging macros whose messages may never be formatted. Displaying it
+    /// formats `self` into a `String` and writes the separated text from
+    /// that to the formatter, honoring any width and alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let message = format!("value: {}", 1234567.separated(policies::COMMA_SEPARATOR));
+    /// assert_eq!( message, "value: 1,234,567" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separated<'a>(&'a self, policy: SeparatorPolicy<'a>) -> Separated<'a, Self>
+    where Self: Sized {
+        Separated::new(self, policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], passing
+    /// each piece of the result to `visitor` instead of building a string.
+    ///
+    /// The pieces are reported in output order: the text before the number,
+    /// its digits one group at a time with the separators between them, and
+    /// the text after it. This lets a caller style or measure each group
+    /// separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{GroupEvent, Separable, policies};
+    ///
+    /// let mut groups = Vec::new();
+    /// 1234567.separate_visit(policies::COMMA_SEPARATOR, &mut |event| {
+    ///     if let GroupEvent::Digits(digits) = event {
+    ///         groups.push(digits.to_owned());
+    ///     }
+    /// });
+    ///
+    /// assert_eq!( groups, ["1", "234", "567"] );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_visit<F>(&self, policy: SeparatorPolicy, visitor: &mut F)
+    where F: FnMut(GroupEvent), Self: Sized {
+        visit::visit(&display::text_of(self), policy, visitor)
+    }
+
+    /// Groups the digits according to the given [`SeparatorPolicy`], leaving
+    /// the separators to the caller.
+    ///
+    /// Each character of the result other than the separators, including any
+    /// text before and after the number, is passed to `write_char`. At each
+    /// boundary between groups, `write_sep` is called with the boundary’s
+    /// number, counting from the right starting at zero, and can write
+    /// whatever it likes there. This is the most flexible way to separate a
+    /// number; the other methods could all be built on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use thousands::{Separable, policies};
+    ///
+    /// let out = RefCell::new(String::new());
+    /// 1234567890.separate_stream(policies::COMMA_SEPARATOR,
+    ///     |c| out.borrow_mut().push(c),
+    ///     |boundary| out.borrow_mut().push_str(if boundary == 2 { "'" } else { "," }));
+    ///
+    /// assert_eq!( out.into_inner(), "1'234,567,890" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_stream<C, S>(&self, policy: SeparatorPolicy, write_char: C, write_sep: S)
+    where C: FnMut(char), S: FnMut(usize), Self: Sized {
+        stream::separate_stream(&display::text_of(self), policy, write_char, write_sep)
+    }
+
+    /// Groups the digits according to the given [`SeparatorPolicy`] and wraps
+    /// each group in brackets instead of separating them, to show how the
+    /// policy groups a number.
+    ///
+    /// Any text before and after the number is kept, and a group left empty
+    /// by a zero in the policy’s `groups` shows as `[]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatorPolicy, policies};
+    ///
+    /// assert_eq!( 1234567.separate_debug(policies::COMMA_SEPARATOR), "[1][234][567]" );
+    ///
+    /// let indian = SeparatorPolicy { groups: &[3, 2], .. policies::COMMA_SEPARATOR };
+    /// assert_eq!( (-1234567.5).separate_debug(indian), "-[12][34][567].5" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_debug(&self, policy: SeparatorPolicy) -> String {
+        display::separate_debug(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], then
+    /// quotes the result for use as a CSV field if it needs it.
+    ///
+    /// Following RFC 4180, the field is wrapped in double quotes (with any
+    /// double quotes inside it doubled) when it contains a comma, a double
+    /// quote, or a line break, which happens when grouping with commas.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( 12345.separate_csv_cell(policies::COMMA_SEPARATOR), "\"12,345\"" );
+    /// assert_eq!( 12345.separate_csv_cell(policies::SPACE_SEPARATOR), "12 345" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_csv_cell(&self, policy: SeparatorPolicy) -> String {
+        display::csv_cell(self.separate_by_policy(policy))
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result along with the byte ranges of its parts.
+    ///
+    /// Each digit gets a range of its own, as does each separator, along
+    /// with any zero-width joiner after it. Runs of other text, such as signs
+    /// and decimal points, are combined. The ranges cover the result in
+    /// order, which lets a user interface compare two results digit by digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, RangeKind, policies};
+    ///
+    /// let (text, ranges) = (-1234).separate_ranges(policies::COMMA_SEPARATOR);
+    ///
+    /// assert_eq!( text, "-1,234" );
+    /// assert_eq!( ranges[0], (0 .. 1, RangeKind::Other) );
+    /// assert_eq!( ranges[1], (1 .. 2, RangeKind::Digit) );
+    /// assert_eq!( ranges[2], (2 .. 3, RangeKind::Separator) );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_ranges(&self, policy: SeparatorPolicy) -> (String, Vec<(Range<usize>, RangeKind)>) {
+        ranges::separate_ranges(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result along with its width in characters and in bytes.
+    ///
+    /// The two differ when the result has non-ASCII characters, such as a
+    /// narrow no-break space separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatorPolicy, policies};
+    ///
+    /// let policy = SeparatorPolicy {
+    ///     separator: "\u{202F}",
+    ///     .. policies::COMMA_SEPARATOR
+    /// };
+    ///
+    /// let measured = 1234567.separate_measured(policy);
+    /// assert_eq!( measured.text,  "1\u{202F}234\u{202F}567" );
+    /// assert_eq!( measured.chars, 9 );
+    /// assert_eq!( measured.bytes, 13 );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_measured(&self, policy: SeparatorPolicy) -> Measured {
+        Measured::new(self.separate_by_policy(policy))
+    }
+
+    /// Splits the number into its sign, its integer part with separators
+    /// added according to the given [`SeparatorPolicy`], and its fractional
+    /// part, for laying them out separately.
+    ///
+    /// The sign is a `-`, `+`, or `−` (U+2212) directly before the number.
+    /// Any other text around the number is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, SeparatedParts, policies};
+    ///
+    /// assert_eq!( (-1234.5).separate_parts(policies::COMMA_SEPARATOR),
+    ///             SeparatedParts {
+    ///                 sign:     Some('-'),
+    ///                 integer:  "1,234".to_owned(),
+    ///                 fraction: Some("5".to_owned()),
+    ///             } );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_parts(&self, policy: SeparatorPolicy) -> SeparatedParts {
+        parts::separate_parts(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], and a `+`
+    /// if the number is positive, for showing a change in value. Also
+    /// returns the number’s [`Sign`], which a dashboard might map to a color.
+    ///
+    /// A zero, like `0` or `-0.00`, is left unsigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, Sign, policies};
+    ///
+    /// let policy = policies::COMMA_SEPARATOR;
+    ///
+    /// assert_eq!( 1234.separate_delta(policy),    ("+1,234".to_owned(), Sign::Positive) );
+    /// assert_eq!( (-1234).separate_delta(policy), ("-1,234".to_owned(), Sign::Negative) );
+    /// assert_eq!( 0.separate_delta(policy),       ("0".to_owned(), Sign::Zero) );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    /// [`Sign`]: enum.Sign.html
+    fn separate_delta(&self, policy: SeparatorPolicy) -> (String, Sign) {
+        delta::separate_delta(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result as an `Arc<str>` for sharing between threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use thousands::{Separable, policies};
+    ///
+    /// let shared: Arc<str> = 12345.separate_arc(policies::COMMA_SEPARATOR);
+    /// assert_eq!( &*shared, "12,345" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_arc(&self, policy: SeparatorPolicy) -> Arc<str> {
+        self.separate_by_policy(policy).into()
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result as an `Rc<str>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::rc::Rc;
+    /// use thousands::{Separable, policies};
+    ///
+    /// let shared: Rc<str> = 12345.separate_rc(policies::COMMA_SEPARATOR);
+    /// assert_eq!( &*shared, "12,345" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_rc(&self, policy: SeparatorPolicy) -> Rc<str> {
+        self.separate_by_policy(policy).into()
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result as UTF-16 code units, without a byte order mark.
+    ///
+    /// This suits APIs, such as those of Windows, that take UTF-16 strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let wide = 12345.separate_utf16(policies::COMMA_SEPARATOR);
+    /// assert_eq!( String::from_utf16(&wide).unwrap(), "12,345" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_utf16(&self, policy: SeparatorPolicy) -> Vec<u16> {
+        self.separate_by_policy(policy).encode_utf16().collect()
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result along with a hash of it, for use as a cache key.
+    ///
+    /// The hash is the one that `String`’s `Hash` impl gives with the
+    /// standard library’s `DefaultHasher`, so equal results always have equal
+    /// hashes within a program. The standard library doesn’t promise that
+    /// its hasher stays the same between releases, so the hash shouldn’t be
+    /// stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// let (text, hash) = 1234567.separate_hashed(policies::COMMA_SEPARATOR);
+    ///
+    /// assert_eq!( text, "1,234,567" );
+    /// assert_eq!( hash, "1234567".separate_hashed(policies::COMMA_SEPARATOR).1 );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_hashed(&self, policy: SeparatorPolicy) -> (String, u64) {
+        let result     = self.separate_by_policy(policy);
+        let mut hasher = DefaultHasher::new();
+        result.hash(&mut hasher);
+        let hash = hasher.finish();
+        (result, hash)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result along with whether it differs from the unseparated text,
+    /// so that callers can skip re-rendering values that didn’t change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( 12.separate_tracked(policies::COMMA_SEPARATOR),
+    ///             ("12".to_owned(), false) );
+    /// assert_eq!( 1234.separate_tracked(policies::COMMA_SEPARATOR),
+    ///             ("1,234".to_owned(), true) );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_tracked(&self, policy: SeparatorPolicy) -> (String, bool) {
+        let text    = display::text_of(self);
+        let result  = text.separate_by_policy(policy);
+        let changed = result != text;
+        (result, changed)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`], returning
+    /// the result with its characters in reverse order, least significant
+    /// digit first. Separators of more than one character are kept whole, so
+    /// `<>` stays `<>`.
+    ///
+    /// This suits rendering code that draws from the right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( 1234567.separate_reversed(policies::COMMA_SEPARATOR), "765,432,1" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    fn separate_reversed(&self, policy: SeparatorPolicy) -> String {
+        display::separate_reversed(&display::text_of(self), policy)
+    }
+
+    /// Adds separators according to the given [`SeparatorPolicy`] to a number
+    /// whose characters are in reverse order, least significant digit first,
+    /// returning the result in reverse order too.
+    ///
+    /// This complements [`separate_reversed`] for code that already holds
+    /// its digits reversed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use thousands::{Separable, policies};
+    ///
+    /// assert_eq!( "7654321".separate_from_reversed(policies::COMMA_SEPARATOR), "765,432,1" );
+    /// ```
+    ///
+    /// [`SeparatorPolicy`]: struct.SeparatorPolicy.html
+    /// [`separate_reversed`]: #method.separate_reversed
+    fn separate_from_reversed(&self, policy: SeparatorPolicy) -> String {
+        display::separate_from_reversed(&display::text_of(self), policy)
+    }
+}
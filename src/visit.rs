@@ -0,0 +1,147 @@
+use super::{CodePlacement, SeparatorPolicy};
+use super::stream::{Piece, walk, with_formatted_after};
+
+/// A piece of a separated number, as reported by
+/// [`Separable::separate_visit`](trait.Separable.html#method.separate_visit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupEvent<'a> {
+    /// The text before the first run of digits.
+    Before(&'a str),
+    /// Some digits of a group. A group is reported in more than one piece
+    /// when it contains characters that the policy ignores.
+    Digits(&'a str),
+    /// A separator between two groups, or the zero-width joiner that follows
+    /// it when the policy’s `bind_with_zwj` is set, or the ellipsis in place
+    /// of groups left out by its `elide_middle`.
+    Separator(&'a str),
+    /// The text after the first run of digits, with the policy’s fraction
+    /// padding and exponent formatting applied.
+    After(&'a str),
+}
+
+/// Reports the pieces of `s`, separated according to `policy`, to
+/// `visitor` in output order. Empty `Before` and `After` text isn’t
+/// reported.
+pub fn visit<F>(s: &str, policy: SeparatorPolicy, visitor: &mut F)
+where F: FnMut(GroupEvent) {
+
+    walk(s, &policy, |piece| match piece {
+        Piece::Before(text)       => visitor(GroupEvent::Before(text)),
+        Piece::Digits(digits)     => match policy.map_digits {
+            Some(map) => visitor(GroupEvent::Digits(&digits.chars().map(map).collect::<String>())),
+            None      => visitor(GroupEvent::Digits(digits)),
+        },
+        Piece::Boundary(boundary) => {
+            visitor(GroupEvent::Separator(policy.separator_at(boundary)));
+            if !policy.joiner().is_empty() {
+                visitor(GroupEvent::Separator(policy.joiner()));
+            }
+        }
+        Piece::Elision      => visitor(GroupEvent::Separator(policy.ellipsis())),
+        Piece::After(after) => with_formatted_after(after, &policy, |text| {
+            if !text.is_empty() {
+                visitor(GroupEvent::After(text));
+            }
+        }),
+        Piece::PowerOfTen(exponent) => {
+            visitor(GroupEvent::Digits("10"));
+            visitor(GroupEvent::After(&format!("^{}", exponent)));
+        }
+        Piece::Currency     => match policy.iso_currency {
+            Some((code, CodePlacement::Before)) => visitor(GroupEvent::Before(code)),
+            Some((code, CodePlacement::After))  => visitor(GroupEvent::After(code)),
+            None                                => (),
+        },
+        Piece::Rest(text)   => visitor(GroupEvent::After(text)),
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::GroupEvent;
+    use super::GroupEvent::*;
+
+    fn events<S: Separable>(value: S, policy: SeparatorPolicy) -> Vec<String> {
+        let mut result = Vec::new();
+        value.separate_visit(policy, &mut |event| result.push(describe(event)));
+        result
+    }
+
+    fn describe(event: GroupEvent) -> String {
+        match event {
+            Before(s)    => format!("before {}", s),
+            Digits(s)    => format!("digits {}", s),
+            Separator(s) => format!("sep {}", s),
+            After(s)     => format!("after {}", s),
+        }
+    }
+
+    #[test]
+    fn negative_fraction() {
+        assert_eq!( events(-1234.5, policies::COMMA_SEPARATOR),
+                    ["before -", "digits 1", "sep ,", "digits 234", "after .5"] );
+        assert_eq!( events("-1234.5", policies::COMMA_SEPARATOR),
+                    ["before -", "digits 1", "sep ,", "digits 234", "after .5"] );
+    }
+
+    #[test]
+    fn no_before_or_after() {
+        assert_eq!( events(1234567, policies::SPACE_SEPARATOR),
+                    ["digits 1", "sep  ", "digits 234", "sep  ", "digits 567"] );
+        assert_eq!( events(123, policies::SPACE_SEPARATOR),
+                    ["digits 123"] );
+        assert_eq!( events("abc", policies::SPACE_SEPARATOR),
+                    ["before abc"] );
+    }
+
+    #[test]
+    fn ignorable_splits_digits() {
+        let policy = SeparatorPolicy {
+            ignorable: &['\''],
+            .. policies::COMMA_SEPARATOR
+        };
+
+        assert_eq!( events("12'34567", policy),
+                    ["digits 1", "sep ,", "digits 2", "digits 34", "sep ,", "digits 567"] );
+    }
+
+    #[test]
+    fn matches_separate_by_policy() {
+        let policy = SeparatorPolicy {
+            fraction_pad:         Some((3, '0')),
+            superscript_exponent: true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        for input in &["x=1234567.5e3 m", "-98765", "no digits", "1234567."] {
+            let mut joined = String::new();
+            input.separate_visit(policy, &mut |event| match event {
+                Before(s) | Digits(s) | Separator(s) | After(s) => joined.push_str(s),
+            });
+            assert_eq!( joined, input.separate_by_policy(policy) );
+        }
+    }
+}
+
+#[cfg(test)]
+mod ascii_only_test {
+    use super::super::{Separable, SeparatorPolicy, policies};
+    use super::GroupEvent::*;
+
+    #[test]
+    fn no_empty_joiner() {
+        let policy = SeparatorPolicy {
+            bind_with_zwj: true,
+            ascii_only:    true,
+            .. policies::COMMA_SEPARATOR
+        };
+
+        let mut separators = Vec::new();
+        1234567.separate_visit(policy, &mut |event| if let Separator(s) = event {
+            separators.push(s.to_owned());
+        });
+
+        assert_eq!( separators, [",", ","] );
+    }
+}